@@ -0,0 +1,347 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+
+/**
+ * what a key means, screens react to these instead of to raw keys
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Next,
+    Prev,
+    Confirm,
+    Undo,
+    Quit,
+    ZoomIn,
+    ZoomOut,
+    PanLeft,
+    PanRight,
+    PanUp,
+    PanDown,
+    ResetMap,
+    ToggleFraming,
+    Help,
+    Yes,
+    No,
+    SwipeLeft,
+    SwipeRight,
+    Favorite,
+    Block,
+    Search,
+    Sort,
+    ReverseSort,
+    OpenLink,
+    CopyLink,
+    PrintLink,
+    // the number keys, always bound and not configurable
+    #[serde(skip)]
+    Choose(usize),
+}
+
+impl Action {
+    pub const CONFIGURABLE: [Action; 26] = [
+        Action::Next,
+        Action::Prev,
+        Action::Confirm,
+        Action::Undo,
+        Action::Quit,
+        Action::ZoomIn,
+        Action::ZoomOut,
+        Action::PanLeft,
+        Action::PanRight,
+        Action::PanUp,
+        Action::PanDown,
+        Action::ResetMap,
+        Action::ToggleFraming,
+        Action::Help,
+        Action::Yes,
+        Action::No,
+        Action::SwipeLeft,
+        Action::SwipeRight,
+        Action::Favorite,
+        Action::Block,
+        Action::Search,
+        Action::Sort,
+        Action::ReverseSort,
+        Action::OpenLink,
+        Action::CopyLink,
+        Action::PrintLink,
+    ];
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Action::Next => "next choice",
+            Action::Prev => "previous choice",
+            Action::Confirm => "confirm the choice, done swiping",
+            Action::Undo => "take back the last swipe",
+            Action::Quit => "quit",
+            Action::ZoomIn => "zoom the map in",
+            Action::ZoomOut => "zoom the map out",
+            Action::PanLeft => "move the map west",
+            Action::PanRight => "move the map east",
+            Action::PanUp => "move the map north",
+            Action::PanDown => "move the map south",
+            Action::ResetMap => "reset the map",
+            Action::ToggleFraming => "fit everything or center on the restaurant",
+            Action::Help => "this help",
+            Action::Yes => "answer yes, shortlist when swiping",
+            Action::No => "answer no, skip when swiping",
+            Action::SwipeLeft => "skip the restaurant",
+            Action::SwipeRight => "shortlist the restaurant",
+            Action::Favorite => "toggle the restaurant as a favorite",
+            Action::Block => "never show the restaurant again",
+            Action::Search => "search the venues by name and category",
+            Action::Sort => "sort the venues by the next column",
+            Action::ReverseSort => "reverse the sort order",
+            Action::OpenLink => "open the picked restaurant in the browser",
+            Action::CopyLink => "copy the picked restaurant's link",
+            Action::PrintLink => "print the picked restaurant's link on the way out",
+            Action::Choose(_) => "pick a choice by its number",
+        }
+    }
+
+    /**
+     * the name the config file knows the action by, "pan_down" for PanDown
+     */
+    pub fn name(&self) -> String {
+        let mut name = String::new();
+        for char in format!("{:?}", self).chars() {
+            if char.is_ascii_uppercase() && !name.is_empty() {
+                name.push('_');
+            }
+            name.push(char.to_ascii_lowercase());
+        }
+        name
+    }
+
+    fn default_keys(&self) -> &'static [&'static str] {
+        match self {
+            Action::Next => &["down", "j"],
+            Action::Prev => &["up", "k"],
+            Action::Confirm => &["enter"],
+            Action::Undo => &["backspace", "u"],
+            // raw mode swallows the interrupt signal, ctrl-c arrives as a key like any other
+            Action::Quit => &["esc", "q", "ctrl-c"],
+            Action::ZoomIn => &["+", "="],
+            Action::ZoomOut => &["-"],
+            Action::PanLeft => &["H"],
+            Action::PanRight => &["L"],
+            Action::PanUp => &["K"],
+            Action::PanDown => &["J"],
+            Action::ResetMap => &["r"],
+            Action::ToggleFraming => &["c"],
+            Action::Help => &["?"],
+            Action::Yes => &["y"],
+            Action::No => &["n"],
+            // only swiping reads the arrows sideways
+            Action::SwipeLeft => &["left"],
+            Action::SwipeRight => &["right"],
+            Action::Favorite => &["f"],
+            Action::Block => &["b"],
+            Action::Search => &["/"],
+            Action::Sort => &["s"],
+            Action::ReverseSort => &["S"],
+            Action::OpenLink => &["o"],
+            Action::CopyLink => &["Y"],
+            Action::PrintLink => &["p"],
+            Action::Choose(_) => &[],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    /**
+     * parses "j", "enter", "ctrl-c" and the like
+     */
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let value = value.trim();
+        let (modifiers, key) = match value.get(..5) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("ctrl-") && value.len() > 5 => {
+                (KeyModifiers::CONTROL, &value[5..])
+            }
+            _ => (KeyModifiers::NONE, value),
+        };
+
+        let code = match key.to_lowercase().as_str() {
+            "enter" => KeyCode::Enter,
+            "esc" => KeyCode::Esc,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "backspace" => KeyCode::Backspace,
+            "tab" => KeyCode::Tab,
+            "space" => KeyCode::Char(' '),
+            _ => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(char), None) => KeyCode::Char(char),
+                    _ => return Err(format!("unknown key: {}", value)),
+                }
+            }
+        };
+
+        Ok(KeyBinding { code, modifiers })
+    }
+
+    fn from_event(key: &KeyEvent) -> Self {
+        // shift is already part of the character, "?" comes with it on most terminals
+        let modifiers = match key.code {
+            KeyCode::Char(_) => key.modifiers - KeyModifiers::SHIFT,
+            _ => key.modifiers,
+        };
+
+        KeyBinding {
+            code: key.code,
+            modifiers,
+        }
+    }
+}
+
+impl fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "ctrl-")?;
+        }
+
+        match self.code {
+            KeyCode::Enter => write!(f, "enter"),
+            KeyCode::Esc => write!(f, "esc"),
+            KeyCode::Up => write!(f, "up"),
+            KeyCode::Down => write!(f, "down"),
+            KeyCode::Left => write!(f, "left"),
+            KeyCode::Right => write!(f, "right"),
+            KeyCode::Backspace => write!(f, "backspace"),
+            KeyCode::Tab => write!(f, "tab"),
+            KeyCode::Char(' ') => write!(f, "space"),
+            KeyCode::Char(char) => write!(f, "{}", char),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+/**
+ * the keys bound to every action, the defaults with the user's overrides on top
+ */
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<Action, Vec<KeyBinding>>,
+}
+
+impl Keymap {
+    /**
+     * an action listed in the overrides loses its default keys, a key bound to two actions is an error
+     */
+    pub fn new(overrides: &HashMap<Action, Vec<String>>) -> Result<Self, String> {
+        let mut bindings = HashMap::new();
+        let mut bound_to = HashMap::new();
+
+        for action in Action::CONFIGURABLE {
+            let keys = match overrides.get(&action) {
+                Some(keys) => keys
+                    .iter()
+                    .map(|key| KeyBinding::parse(key))
+                    .collect::<Result<Vec<_>, _>>()?,
+                None => action
+                    .default_keys()
+                    .iter()
+                    .map(|key| KeyBinding::parse(key))
+                    .collect::<Result<Vec<_>, _>>()?,
+            };
+
+            for key in &keys {
+                match bound_to.insert(*key, action) {
+                    Some(other) if other != action => {
+                        return Err(format!(
+                            "{} is bound to both {} and {}",
+                            key,
+                            other.name(),
+                            action.name()
+                        ))
+                    }
+                    _ => {}
+                }
+            }
+
+            bindings.insert(action, keys);
+        }
+
+        Ok(Keymap { bindings })
+    }
+
+    pub fn action(&self, key: &KeyEvent) -> Option<Action> {
+        let binding = KeyBinding::from_event(key);
+
+        if let Some(action) = Action::CONFIGURABLE
+            .into_iter()
+            .find(|action| self.bindings[action].contains(&binding))
+        {
+            return Some(action);
+        }
+
+        match binding.code {
+            KeyCode::Char(digit @ '1'..='9') if binding.modifiers.is_empty() => {
+                Some(Action::Choose(digit as usize - '1' as usize))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn keys(&self, action: Action) -> &[KeyBinding] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /**
+     * "y to like, n to dislike" out of the first key bound to each action,
+     * actions left without a key are left out
+     */
+    pub fn hint(&self, entries: &[(Action, &str)]) -> String {
+        entries
+            .iter()
+            .filter_map(|(action, what)| {
+                self.keys(*action)
+                    .first()
+                    .map(|key| format!("{} {}", key, what))
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hints_at_the_keys_actually_bound() {
+        let overrides =
+            HashMap::from([(Action::Yes, vec![String::from("l")]), (Action::No, vec![])]);
+        let keymap = Keymap::new(&overrides).unwrap();
+
+        let hint = keymap.hint(&[
+            (Action::Yes, "to like"),
+            (Action::No, "to dislike"),
+            (Action::Confirm, "to start"),
+        ]);
+
+        assert_eq!(hint, "l to like, enter to start");
+    }
+
+    #[test]
+    fn rejects_a_key_bound_to_two_actions() {
+        let overrides = HashMap::from([(Action::PanDown, vec![String::from("j")])]);
+
+        assert_eq!(
+            Keymap::new(&overrides).unwrap_err(),
+            "j is bound to both next and pan_down"
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::io::{stdout, Result, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/**
+ * opens the link with whatever the system opens links with, without waiting on it
+ */
+pub fn open(url: &str) -> Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        // the empty argument is the window title `start` would take the url for
+        command.args(["/C", "start", ""]);
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+
+    // whatever the opener prints would scribble over the ui
+    let mut child = command
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    // reaped on the side, so it doesn't linger as a zombie while the ui runs
+    thread::spawn(move || child.wait());
+
+    Ok(())
+}
+
+/**
+ * puts the text on the clipboard through the terminal with OSC 52,
+ * works over ssh too, as long as the terminal allows it
+ */
+pub fn copy(text: &str) -> Result<()> {
+    let mut stdout = stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", base64(text.as_bytes()))?;
+    stdout.flush()
+}
+
+fn base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let triple = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |triple, (index, byte)| {
+                triple | u32::from(*byte) << (16 - index * 8)
+            });
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (triple >> (18 - index * 6)) & 0x3f;
+                encoded.push(char::from(BASE64_ALPHABET[sextet as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_the_rfc_4648_vectors() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foob"), "Zm9vYg==");
+        assert_eq!(base64(b"fooba"), "Zm9vYmE=");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+}
@@ -1,5 +1,24 @@
-mod restaurant_display;
-pub use restaurant_display::RestaurantDisplayApp;
+mod keymap;
+pub use keymap::Action;
 
-mod end_screen;
-pub use end_screen::EndScreenApp;
+mod restaurant_view;
+pub use restaurant_view::RestaurantView;
+
+mod runtime;
+pub use runtime::{is_cancelled, App, EXIT_CANCELLED};
+
+mod terminal;
+
+mod link;
+
+mod settings;
+pub use settings::Settings;
+
+mod theme;
+pub use theme::ThemeOverrides;
+
+mod screens;
+pub use screens::{
+    BrowseScreen, CategoryScreen, ComparisonScreen, EndScreen, MatchupScreen, QuestionScreen,
+    SwipeChoice, SwipeScreen, WaitingScreen,
+};
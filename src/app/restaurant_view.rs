@@ -0,0 +1,989 @@
+use crossterm::event::{Event, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{
+    prelude::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{
+        canvas::{self, Canvas, Map, Painter, Points, Shape},
+        Block, Borders, Paragraph, Wrap,
+    },
+    Frame,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::app::keymap::Action;
+use crate::app::theme::Theme;
+use crate::controllers::WoltAPITypes::ResterauntItem;
+use crate::geo::{Basemap, Raster, Route};
+use crate::selection::Preferences;
+
+// the zoom is half the map's height, in degrees of latitude
+const MAX_MAP_ZOOM_OUT_DISTANCE: f64 = 20.0;
+const MIN_MAP_ZOOM_OUT_DISTANCE: f64 = 0.002;
+// about a kilometer around the restaurant when centering on it
+const CENTERED_ZOOM: f64 = 0.01;
+const ZOOM_ANIMATION_STEP: f64 = 0.9;
+// room around the framed points so they don't sit on the edge
+const FIT_PADDING: f64 = 1.25;
+// terminal cells are about twice as tall as they are wide
+const CELL_ASPECT: f64 = 0.5;
+// the longitude scale blows up towards the poles
+const MAX_LATITUDE: f64 = 85.0;
+const KM_PER_DEGREE: f64 = 111.32;
+const SCALE_BAR_LENGTHS_KM: [f64; 15] = [
+    0.05, 0.1, 0.2, 0.5, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 2000.0,
+];
+const MAP_ZOOM_STEP: f64 = 1.5;
+// a keyboard pan moves the map by this much of its half width
+const MAP_PAN_STEP: f64 = 0.25;
+
+// below this the map goes under the question instead of next to it
+const SIDE_BY_SIDE_MIN_WIDTH: u16 = 100;
+// below these the map would be too small to make anything out
+const MAP_MIN_WIDTH: u16 = 40;
+const STACKED_MAP_MIN_HEIGHT: u16 = 30;
+
+pub const MATCHUP_LABELS: [&str; 2] = ["A", "B"];
+/**
+ * what `handle_map_action` reacts to
+ */
+pub const MAP_ACTIONS: [Action; 8] = [
+    Action::ZoomIn,
+    Action::ZoomOut,
+    Action::PanLeft,
+    Action::PanRight,
+    Action::PanUp,
+    Action::PanDown,
+    Action::ResetMap,
+    Action::ToggleFraming,
+];
+const PROPOSED_SYMBOL: &str = "◉";
+const CANDIDATE_SYMBOL: &str = "•";
+const HOME_SYMBOL: &str = "⌂";
+// without colors this is all that tells the venues ruled out apart from the rest
+const FILTERED_OUT_SYMBOL: &str = "×";
+const LEGEND_WIDTH: u16 = 16;
+// landmark names only fit on the map this far in
+const LANDMARK_LABEL_ZOOM: f64 = 0.005;
+
+/**
+ * what the map is centered on, panning moves away from it
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapFraming {
+    // the address and every restaurant, zoomed in as far as they all fit
+    Fit,
+    Center,
+}
+
+/*
+ * cells of an already rasterized base map, painted as they are
+ */
+struct Cells<'a> {
+    cells: &'a [(usize, usize)],
+    color: Color,
+}
+
+impl Shape for Cells<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        for (x, y) in self.cells {
+            painter.paint(*x, *y, self.color);
+        }
+    }
+}
+
+pub struct RestaurantLayout {
+    pub top: Rect,
+    pub choices: Rect,
+    pub map: Option<Rect>,
+}
+
+/**
+ * the rendering pieces shared by every restaurant screen, and the map zoom
+ * which carries over from one question to the next
+ */
+pub struct RestaurantView {
+    address: (f32, f32),
+    current_zoom: f64,
+    // once zoomed by hand the map stops zooming in on its own
+    zoomed_by_hand: bool,
+    // carries over between questions, unlike the zoom and the pan
+    framing: MapFraming,
+    // how far the map center was moved away from the framing's center, in (lon, lat)
+    pan: (f64, f64),
+    // half the width and height the map last spanned, in degrees
+    extent: (f64, f64),
+    drag_origin: Option<(u16, u16)>,
+    // where the choices and the map were last drawn, for the mouse to hit
+    choices_area: Rect,
+    map_area: Option<Rect>,
+    // every other venue around, faint on the map behind the proposed ones
+    matching_candidates: Vec<(f64, f64)>,
+    filtered_out_candidates: Vec<(f64, f64)>,
+    // the city's streets under everything, redrawn only when the map moves
+    basemap: Option<Arc<Basemap>>,
+    basemap_raster: Raster,
+    // walks from the address along the streets, by the restaurant coordinates
+    walking_routes: HashMap<(u64, u64), Option<Route>>,
+}
+
+impl RestaurantView {
+    pub fn new(address: (f32, f32), basemap: Option<Arc<Basemap>>) -> Self {
+        RestaurantView {
+            address,
+            current_zoom: MAX_MAP_ZOOM_OUT_DISTANCE,
+            zoomed_by_hand: false,
+            framing: MapFraming::Fit,
+            pan: (0.0, 0.0),
+            extent: (MAX_MAP_ZOOM_OUT_DISTANCE, MAX_MAP_ZOOM_OUT_DISTANCE),
+            drag_origin: None,
+            choices_area: Rect::default(),
+            map_area: None,
+            matching_candidates: vec![],
+            filtered_out_candidates: vec![],
+            basemap,
+            basemap_raster: Raster::default(),
+            walking_routes: HashMap::new(),
+        }
+    }
+
+    /**
+     * zooms all the way out, the map animates back in from there
+     */
+    pub fn reset_zoom(&mut self) {
+        self.current_zoom = MAX_MAP_ZOOM_OUT_DISTANCE;
+        self.zoomed_by_hand = false;
+        self.pan = (0.0, 0.0);
+    }
+
+    fn zoom_by(&mut self, factor: f64) {
+        self.current_zoom = (self.current_zoom * factor)
+            .clamp(MIN_MAP_ZOOM_OUT_DISTANCE, MAX_MAP_ZOOM_OUT_DISTANCE);
+        self.zoomed_by_hand = true;
+    }
+
+    fn pan_by(&mut self, columns: f64, rows: f64) {
+        self.pan.0 += columns * self.extent.0 * MAP_PAN_STEP;
+        self.pan.1 += rows * self.extent.1 * MAP_PAN_STEP;
+        self.zoomed_by_hand = true;
+    }
+
+    /**
+     * zooms, pans, resets or reframes the map, returns whether the action was one of those
+     */
+    pub fn handle_map_action(&mut self, action: Option<Action>) -> bool {
+        match action {
+            Some(Action::ZoomIn) => self.zoom_by(1.0 / MAP_ZOOM_STEP),
+            Some(Action::ZoomOut) => self.zoom_by(MAP_ZOOM_STEP),
+            Some(Action::PanLeft) => self.pan_by(-1.0, 0.0),
+            Some(Action::PanRight) => self.pan_by(1.0, 0.0),
+            Some(Action::PanUp) => self.pan_by(0.0, 1.0),
+            Some(Action::PanDown) => self.pan_by(0.0, -1.0),
+            // the entry animation plays again from all the way out
+            Some(Action::ResetMap) => self.reset_zoom(),
+            Some(Action::ToggleFraming) => {
+                self.framing = match self.framing {
+                    MapFraming::Fit => MapFraming::Center,
+                    MapFraming::Center => MapFraming::Fit,
+                };
+                self.reset_zoom();
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    /**
+     * the wheel zooms and dragging pans the map, returns the index of a
+     * clicked choice out of the `choices_len` drawn by `render_choices`
+     */
+    pub fn handle_mouse(&mut self, event: &Event, choices_len: usize) -> Option<usize> {
+        let Event::Mouse(MouseEvent {
+            kind, column, row, ..
+        }) = *event
+        else {
+            return None;
+        };
+
+        let on_map = self
+            .map_area
+            .is_some_and(|area| RestaurantView::contains(area, column, row));
+
+        match kind {
+            MouseEventKind::ScrollUp if on_map => self.zoom_by(1.0 / MAP_ZOOM_STEP),
+            MouseEventKind::ScrollDown if on_map => self.zoom_by(MAP_ZOOM_STEP),
+            MouseEventKind::Down(MouseButton::Left) if on_map => {
+                self.drag_origin = Some((column, row))
+            }
+            MouseEventKind::Drag(MouseButton::Left) => self.drag_to(column, row),
+            MouseEventKind::Up(MouseButton::Left) => self.drag_origin = None,
+            MouseEventKind::Down(MouseButton::Left)
+                if RestaurantView::contains(self.choices_area, column, row) =>
+            {
+                // the first line of the choices is the border
+                let index = usize::from(row - self.choices_area.y).checked_sub(1)?;
+                return (index < choices_len).then_some(index);
+            }
+            _ => {}
+        }
+
+        None
+    }
+
+    fn contains(area: Rect, column: u16, row: u16) -> bool {
+        column >= area.x && column < area.right() && row >= area.y && row < area.bottom()
+    }
+
+    fn drag_to(&mut self, column: u16, row: u16) {
+        let (Some((origin_column, origin_row)), Some(area)) = (self.drag_origin, self.map_area)
+        else {
+            return;
+        };
+
+        let degrees_per_column = 2.0 * self.extent.0 / f64::from(area.width.max(1));
+        let degrees_per_row = 2.0 * self.extent.1 / f64::from(area.height.max(1));
+
+        // the map follows the pointer, rows grow downwards while latitude grows upwards
+        self.pan.0 -= (f64::from(column) - f64::from(origin_column)) * degrees_per_column;
+        self.pan.1 += (f64::from(row) - f64::from(origin_row)) * degrees_per_row;
+        self.zoomed_by_hand = true;
+        self.drag_origin = Some((column, row));
+    }
+
+    /**
+     * the venues to show around the proposed ones, split by whether they match the preferences
+     */
+    pub fn set_candidates(&mut self, restaurants: &[ResterauntItem], preferences: &Preferences) {
+        let (matching, filtered_out): (Vec<_>, Vec<_>) = restaurants
+            .iter()
+            .partition(|restaurant| preferences.matches(restaurant));
+
+        let coordinates = |restaurants: Vec<&ResterauntItem>| {
+            restaurants
+                .iter()
+                .map(|restaurant| (restaurant.venue.location[0], restaurant.venue.location[1]))
+                .collect()
+        };
+        self.matching_candidates = coordinates(matching);
+        self.filtered_out_candidates = coordinates(filtered_out);
+    }
+
+    /**
+     * the entry animation, zooms in a step every frame until the framing's zoom
+     */
+    fn animate_zoom(&mut self, target_zoom: f64) {
+        if !self.zoomed_by_hand && self.current_zoom > target_zoom {
+            self.current_zoom = (self.current_zoom * ZOOM_ANIMATION_STEP).max(target_zoom);
+        }
+    }
+
+    /**
+     * returns the center and the zoom that frame the points, padded all around
+     */
+    fn fit_to_bounds(points: &[(f64, f64)], area: Rect) -> ((f64, f64), f64) {
+        let (min_x, max_x, min_y, max_y) = points.iter().fold(
+            (f64::MAX, f64::MIN, f64::MAX, f64::MIN),
+            |(min_x, max_x, min_y, max_y), (x, y)| {
+                (min_x.min(*x), max_x.max(*x), min_y.min(*y), max_y.max(*y))
+            },
+        );
+        let center = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+
+        // the zoom is half the height, the width has to fit in its share too
+        let half_height = (max_y - min_y) / 2.0;
+        let half_width = (max_x - min_x) / 2.0;
+        let zoom = half_height.max(half_width / RestaurantView::half_width(1.0, center.1, area));
+
+        (
+            center,
+            (zoom * FIT_PADDING).clamp(MIN_MAP_ZOOM_OUT_DISTANCE, MAX_MAP_ZOOM_OUT_DISTANCE),
+        )
+    }
+
+    /**
+     * half the map's width in degrees of longitude for `half_height` degrees of latitude,
+     * so that a kilometer on the map is as long across as it is up
+     */
+    fn half_width(half_height: f64, latitude: f64, area: Rect) -> f64 {
+        let aspect = f64::from(area.width) * CELL_ASPECT / f64::from(area.height.max(1));
+        let latitude_scale = latitude
+            .clamp(-MAX_LATITUDE, MAX_LATITUDE)
+            .to_radians()
+            .cos();
+
+        half_height * aspect / latitude_scale
+    }
+
+    pub fn get_coordinates(&self, restaurant: &ResterauntItem) -> ((f64, f64), (f64, f64)) {
+        // the address is kept as (lat, lon), the map goes by (lon, lat) like x and y
+        let address_coordinates: (f64, f64) = (self.address.1.into(), self.address.0.into());
+
+        let restaurant_coordinates_vec = restaurant.venue.location.to_owned();
+        let restaurant_coordinates = (restaurant_coordinates_vec[0], restaurant_coordinates_vec[1]);
+
+        (address_coordinates, restaurant_coordinates)
+    }
+
+    /**
+     * side by side when there's room, stacked on narrow terminals and without
+     * the map once it would be too small to make anything out
+     */
+    pub fn get_restaurant_display(&mut self, area: Rect) -> RestaurantLayout {
+        let layout = RestaurantView::get_restaurant_layout(area);
+        // a hidden map can't be scrolled or dragged
+        self.map_area = layout.map;
+
+        layout
+    }
+
+    fn get_restaurant_layout(area: Rect) -> RestaurantLayout {
+        if area.width >= SIDE_BY_SIDE_MIN_WIDTH {
+            let layout = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(area);
+            let sub_layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(layout[0]);
+
+            return RestaurantLayout {
+                top: sub_layout[0],
+                choices: sub_layout[1],
+                map: Some(layout[1]),
+            };
+        }
+
+        if area.width >= MAP_MIN_WIDTH && area.height >= STACKED_MAP_MIN_HEIGHT {
+            let layout = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Percentage(40),
+                    Constraint::Percentage(20),
+                    Constraint::Percentage(40),
+                ])
+                .split(area);
+
+            return RestaurantLayout {
+                top: layout[0],
+                choices: layout[1],
+                map: Some(layout[2]),
+            };
+        }
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(area);
+
+        RestaurantLayout {
+            top: layout[0],
+            choices: layout[1],
+            map: None,
+        }
+    }
+
+    pub fn get_restaurant_description(
+        preferences: &Preferences,
+        restaurant: &ResterauntItem,
+    ) -> String {
+        let name = &restaurant.title;
+        let favorite = if preferences.venue_lists.is_favorite(&restaurant.venue.slug) {
+            " ★"
+        } else {
+            ""
+        };
+        // the restaurant already passed the dietary filter, marked ones get a badge
+        let badges: String = preferences
+            .dietary_profiles
+            .iter()
+            .filter(|profile| profile.is_marked(restaurant))
+            .map(|profile| format!(" [{}]", profile.name()))
+            .collect();
+        let categories = &restaurant.filtering.filters[0].values;
+        let price = &restaurant.venue.delivery_price;
+        let range = &restaurant.venue.estimate_range;
+        let url = restaurant.wolt_url();
+
+        format!(
+            "{name}{favorite}{badges} \n {range}min - {price} \n {categories} \n {url}",
+            name = name,
+            favorite = favorite,
+            badges = badges,
+            categories = categories.join(", "),
+            price = price,
+            range = range,
+            url = url
+        )
+    }
+
+    fn get_choices_element<'a>(
+        theme: &Theme,
+        choices: &'a [String],
+        choice_index: usize,
+    ) -> Vec<Line<'a>> {
+        let mut lines: Vec<Line> = vec![];
+
+        for (index, choice) in choices.iter().enumerate() {
+            let span = if index == choice_index {
+                Span::styled(format!(">> {}", choice), theme.highlight())
+            } else {
+                Span::styled(format!("   {}", choice), Style::new())
+            };
+
+            lines.push(Line::from(span));
+        }
+
+        lines
+    }
+
+    pub fn render_top_section(f: &mut Frame, theme: &Theme, top_section_text: String, area: Rect) {
+        f.render_widget(
+            Paragraph::new(top_section_text)
+                .alignment(Alignment::Center)
+                .style(theme.banner())
+                .wrap(Wrap { trim: false }),
+            area,
+        );
+    }
+
+    pub fn render_choices(
+        &mut self,
+        f: &mut Frame,
+        theme: &Theme,
+        choices: &[String],
+        choice_index: usize,
+        area: Rect,
+    ) {
+        let choices_element = RestaurantView::get_choices_element(theme, choices, choice_index);
+        self.choices_area = area;
+
+        f.render_widget(
+            Paragraph::new(choices_element).block(Block::default().borders(Borders::ALL)),
+            area,
+        );
+    }
+
+    pub fn render_map(
+        &mut self,
+        f: &mut Frame,
+        theme: &Theme,
+        address_coordinates: (f64, f64),
+        restaurants_coordinates: &[(f64, f64)],
+        area: Rect,
+    ) {
+        let map = Map {
+            resolution: canvas::MapResolution::High,
+            color: theme.map(),
+        };
+
+        let (address_lon, address_lat) = address_coordinates;
+        let ((framing_lon, framing_lat), target_zoom) =
+            match (self.framing, restaurants_coordinates.first()) {
+                (MapFraming::Center, Some(restaurant_coordinates)) => {
+                    (*restaurant_coordinates, CENTERED_ZOOM)
+                }
+                _ => {
+                    let mut points = restaurants_coordinates.to_vec();
+                    points.push(address_coordinates);
+                    RestaurantView::fit_to_bounds(&points, area)
+                }
+            };
+        self.animate_zoom(target_zoom);
+
+        let (center_lon, center_lat) = (framing_lon + self.pan.0, framing_lat + self.pan.1);
+        let zoom_y = self.current_zoom;
+        let zoom_x = RestaurantView::half_width(zoom_y, center_lat, area);
+        self.extent = (zoom_x, zoom_y);
+
+        let x_bounds = [center_lon - zoom_x, center_lon + zoom_x];
+        let y_bounds = [center_lat - zoom_y, center_lat + zoom_y];
+        // a half block cell holds two points, one above the other
+        let resolution = (usize::from(area.width), usize::from(area.height) * 2);
+        if let Some(basemap) = &self.basemap {
+            self.basemap_raster
+                .update(basemap, x_bounds, y_bounds, resolution);
+        }
+        let routes = self.walking_routes(address_coordinates, restaurants_coordinates);
+        let landmarks = match &self.basemap {
+            Some(basemap) if self.current_zoom <= LANDMARK_LABEL_ZOOM => &basemap.landmarks[..],
+            _ => &[],
+        };
+
+        // Displaying the map
+        f.render_widget(
+            Canvas::default()
+                .marker(ratatui::symbols::Marker::HalfBlock)
+                .x_bounds(x_bounds)
+                .y_bounds(y_bounds)
+                .paint(|context| {
+                    context.draw(&Cells {
+                        cells: &self.basemap_raster.outlines,
+                        color: theme.landmarks(),
+                    });
+                    context.draw(&Cells {
+                        cells: &self.basemap_raster.streets,
+                        color: theme.streets(),
+                    });
+                    for landmark in landmarks {
+                        context.print(
+                            landmark.coordinates.0,
+                            landmark.coordinates.1,
+                            Span::styled(landmark.name.clone(), Style::new().fg(theme.landmarks())),
+                        );
+                    }
+                    context.draw(&map);
+                    if theme.no_color() {
+                        let candidates = [
+                            (FILTERED_OUT_SYMBOL, &self.filtered_out_candidates),
+                            (CANDIDATE_SYMBOL, &self.matching_candidates),
+                        ];
+                        for (symbol, coordinates) in candidates {
+                            for (x, y) in coordinates {
+                                context.print(*x, *y, Span::styled(symbol, theme.text()));
+                            }
+                        }
+                    } else {
+                        context.draw(&Points {
+                            color: theme.filtered_out(),
+                            coords: &self.filtered_out_candidates,
+                        });
+                        context.draw(&Points {
+                            color: theme.candidate(),
+                            coords: &self.matching_candidates,
+                        });
+                    }
+
+                    // the proposed restaurants go above everything else
+                    context.layer();
+
+                    for (index, restaurant_coordinates) in
+                        restaurants_coordinates.iter().enumerate()
+                    {
+                        // the walk along the streets when there is one, otherwise straight there
+                        match &routes[index] {
+                            Some(route) => {
+                                for segment in route.points.windows(2) {
+                                    context.draw(&canvas::Line::new(
+                                        segment[0].0,
+                                        segment[0].1,
+                                        segment[1].0,
+                                        segment[1].1,
+                                        theme.route(),
+                                    ));
+                                }
+                            }
+                            None => context.draw(&canvas::Line::new(
+                                address_lon,
+                                address_lat,
+                                restaurant_coordinates.0,
+                                restaurant_coordinates.1,
+                                theme.route(),
+                            )),
+                        }
+
+                        context.draw(&Points {
+                            color: theme.marker(index),
+                            coords: &[*restaurant_coordinates],
+                        });
+
+                        // with several restaurants on the map, label them like the choices
+                        let label = if restaurants_coordinates.len() > 1 {
+                            MATCHUP_LABELS[index % MATCHUP_LABELS.len()]
+                        } else {
+                            PROPOSED_SYMBOL
+                        };
+                        context.print(
+                            restaurant_coordinates.0,
+                            restaurant_coordinates.1,
+                            Span::styled(label, Style::new().fg(theme.marker(index)).bold()),
+                        );
+                    }
+
+                    if theme.no_color() {
+                        context.print(
+                            address_coordinates.0,
+                            address_coordinates.1,
+                            Span::styled(HOME_SYMBOL, theme.text()),
+                        );
+                    } else {
+                        context.draw(&Points {
+                            color: theme.home(),
+                            coords: &[address_coordinates],
+                        });
+                    }
+                }),
+            area,
+        );
+
+        self.render_legend(f, theme, area);
+        self.render_scale(f, theme, center_lat, area);
+        RestaurantView::render_walks(f, theme, &routes, area);
+    }
+
+    /*
+     * the walking routes to the restaurants, worked out once per restaurant
+     */
+    fn walking_routes(
+        &mut self,
+        address_coordinates: (f64, f64),
+        restaurants_coordinates: &[(f64, f64)],
+    ) -> Vec<Option<Route>> {
+        let Some(basemap) = &self.basemap else {
+            return vec![None; restaurants_coordinates.len()];
+        };
+
+        restaurants_coordinates
+            .iter()
+            .map(|coordinates| {
+                self.walking_routes
+                    .entry((coordinates.0.to_bits(), coordinates.1.to_bits()))
+                    .or_insert_with(|| basemap.walking_route(address_coordinates, *coordinates))
+                    .clone()
+            })
+            .collect()
+    }
+
+    /**
+     * how far the walk to every restaurant is, in the top left corner
+     */
+    fn render_walks(f: &mut Frame, theme: &Theme, routes: &[Option<Route>], map_area: Rect) {
+        let lines: Vec<Line> = routes
+            .iter()
+            .enumerate()
+            .filter_map(|(index, route)| {
+                let route = route.as_ref()?;
+                let symbol = if routes.len() > 1 {
+                    MATCHUP_LABELS[index % MATCHUP_LABELS.len()]
+                } else {
+                    PROPOSED_SYMBOL
+                };
+
+                Some(Line::from(vec![
+                    Span::styled(symbol, Style::new().fg(theme.marker(index)).bold()),
+                    Span::styled(
+                        format!(
+                            " {:.1}km walk, {} min",
+                            route.length_km,
+                            route.minutes().round()
+                        ),
+                        theme.text(),
+                    ),
+                ]))
+            })
+            .collect();
+
+        let height = lines.len() as u16;
+        let width = lines.iter().map(Line::width).max().unwrap_or(0) as u16;
+        if height == 0 || map_area.height < height * 3 || map_area.width < width * 2 {
+            return;
+        }
+
+        f.render_widget(
+            Paragraph::new(lines),
+            Rect {
+                x: map_area.x,
+                y: map_area.y,
+                width,
+                height,
+            },
+        );
+    }
+
+    /**
+     * a scale bar in the bottom right corner and north in the top right one
+     */
+    fn render_scale(&self, f: &mut Frame, theme: &Theme, latitude: f64, map_area: Rect) {
+        if map_area.width < 12 || map_area.height < 4 {
+            return;
+        }
+
+        let km_across = 2.0
+            * self.extent.0
+            * KM_PER_DEGREE
+            * latitude
+                .clamp(-MAX_LATITUDE, MAX_LATITUDE)
+                .to_radians()
+                .cos();
+        let km_per_column = km_across / f64::from(map_area.width);
+
+        // the longest round length that still takes up at most a quarter of the map
+        let Some(length_km) = SCALE_BAR_LENGTHS_KM
+            .into_iter()
+            .rev()
+            .find(|length_km| *length_km <= km_across / 4.0)
+        else {
+            return;
+        };
+        let columns = ((length_km / km_per_column).round() as usize).max(2);
+        let label = if length_km < 1.0 {
+            format!(" {}m", (length_km * 1000.0).round())
+        } else {
+            format!(" {}km", length_km)
+        };
+        let scale_bar = format!("├{}┤{}", "─".repeat(columns - 2), label);
+
+        let width = (scale_bar.chars().count() as u16).min(map_area.width);
+        f.render_widget(
+            Paragraph::new(scale_bar).style(theme.text()),
+            Rect {
+                x: map_area.right() - width,
+                y: map_area.bottom() - 1,
+                width,
+                height: 1,
+            },
+        );
+        f.render_widget(
+            Paragraph::new("N\n↑").style(theme.text()),
+            Rect {
+                x: map_area.right() - 1,
+                y: map_area.y,
+                width: 1,
+                height: 2,
+            },
+        );
+    }
+
+    /**
+     * what the dots mean, in the bottom left corner of the map
+     */
+    fn render_legend(&self, f: &mut Frame, theme: &Theme, map_area: Rect) {
+        let mut entries = vec![
+            (PROPOSED_SYMBOL, "proposed", theme.marker(0)),
+            (HOME_SYMBOL, "you", theme.home()),
+        ];
+        if !self.matching_candidates.is_empty() {
+            entries.push((CANDIDATE_SYMBOL, "matches", theme.candidate()));
+        }
+        if !self.filtered_out_candidates.is_empty() {
+            entries.push((FILTERED_OUT_SYMBOL, "filtered out", theme.filtered_out()));
+        }
+
+        let height = entries.len() as u16;
+        // the legend shouldn't cover most of the map
+        if map_area.height < height * 3 || map_area.width < LEGEND_WIDTH * 2 {
+            return;
+        }
+
+        let lines: Vec<Line> = entries
+            .into_iter()
+            .map(|(symbol, label, color)| {
+                Line::from(vec![
+                    Span::styled(symbol, Style::new().fg(color)),
+                    Span::styled(format!(" {}", label), theme.text()),
+                ])
+            })
+            .collect();
+
+        f.render_widget(
+            Paragraph::new(lines),
+            Rect {
+                x: map_area.x,
+                y: map_area.bottom() - height,
+                width: LEGEND_WIDTH,
+                height,
+            },
+        );
+    }
+
+    /**
+     * the question and restaurant on the top left, choices below them and the map on the right,
+     * `coordinates` are the address and restaurant ones from `get_coordinates`
+     */
+    pub fn render_resteraunt_display(
+        &mut self,
+        f: &mut Frame,
+        theme: &Theme,
+        top_section_text: String,
+        choices: &[String],
+        choice_index: usize,
+        coordinates: ((f64, f64), (f64, f64)),
+    ) {
+        let (address_coordinates, restaurant_coordinates) = coordinates;
+        let layout = self.get_restaurant_display(f.size());
+
+        RestaurantView::render_top_section(f, theme, top_section_text, layout.top);
+        self.render_choices(f, theme, choices, choice_index, layout.choices);
+        if let Some(map_area) = layout.map {
+            self.render_map(
+                f,
+                theme,
+                address_coordinates,
+                &[restaurant_coordinates],
+                map_area,
+            )
+        }
+    }
+}
+
+/**
+ * moves the highlighted choice on next/prev, returns true once a choice is made,
+ * either confirmed or picked straight away by its number or by y/n.
+ * with no choices nothing can be made
+ */
+pub fn navigate_choices(
+    action: Option<Action>,
+    choice_index: &mut usize,
+    choices: &[String],
+) -> bool {
+    let choices_len = choices.len();
+    if choices_len == 0 {
+        return false;
+    }
+
+    match action {
+        Some(Action::Confirm) => return true,
+        Some(Action::Next) => {
+            if *choice_index >= choices_len - 1 {
+                *choice_index = 0;
+            } else {
+                *choice_index += 1;
+            }
+        }
+        Some(Action::Prev) => {
+            if *choice_index == 0 || *choice_index >= choices_len {
+                *choice_index = choices_len - 1;
+            } else {
+                *choice_index -= 1;
+            }
+        }
+        Some(Action::Choose(index)) if index < choices_len => {
+            *choice_index = index;
+            return true;
+        }
+        Some(action @ (Action::Yes | Action::No)) => {
+            let answer = if action == Action::Yes { "yes" } else { "no" };
+            if let Some(index) = choices.iter().position(|choice| choice == answer) {
+                *choice_index = index;
+                return true;
+            }
+        }
+        _ => {}
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    /*
+     * draws a restaurant question on a terminal of the size, returns where the map went
+     */
+    fn render(width: u16, height: u16) -> Option<Rect> {
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        let mut view = RestaurantView::new((32.079612, 34.811399), None);
+
+        terminal
+            .draw(|f| {
+                view.render_resteraunt_display(
+                    f,
+                    &Theme::dark(),
+                    String::from("Do you want to eat at"),
+                    &[String::from("yes"), String::from("no")],
+                    0,
+                    ((34.811399, 32.079612), (34.8150, 32.0800)),
+                )
+            })
+            .unwrap();
+
+        view.map_area
+    }
+
+    #[test]
+    fn puts_the_map_beside_the_question_from_100_columns() {
+        let map = render(100, 40).unwrap();
+        assert_eq!((map.x, map.y, map.width, map.height), (50, 0, 50, 40));
+
+        let map = render(99, 40).unwrap();
+        assert_eq!((map.x, map.width), (0, 99));
+        assert!(map.y > 0);
+    }
+
+    #[test]
+    fn stacks_the_map_from_40_columns() {
+        assert!(render(40, 30).is_some());
+        assert!(render(39, 30).is_none());
+    }
+
+    #[test]
+    fn tells_the_venues_apart_without_colors() {
+        let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+        let mut view = RestaurantView::new((32.079612, 34.811399), None);
+        view.matching_candidates = vec![(34.8130, 32.0830)];
+        view.filtered_out_candidates = vec![(34.8090, 32.0770)];
+        view.current_zoom = 0.01;
+        view.zoomed_by_hand = true;
+
+        terminal
+            .draw(|f| {
+                view.render_resteraunt_display(
+                    f,
+                    &Theme::dark().without_colors(),
+                    String::from("Do you want to eat at"),
+                    &[String::from("yes"), String::from("no")],
+                    0,
+                    ((34.811399, 32.079612), (34.8150, 32.0800)),
+                )
+            })
+            .unwrap();
+
+        let count = |symbol: &str| {
+            let buffer = terminal.backend().buffer();
+            buffer
+                .content
+                .iter()
+                .filter(|cell| cell.symbol == symbol)
+                .count()
+        };
+        // each on the map and in the legend
+        assert_eq!(count(HOME_SYMBOL), 2);
+        assert_eq!(count(CANDIDATE_SYMBOL), 2);
+        assert_eq!(count(FILTERED_OUT_SYMBOL), 2);
+    }
+
+    #[test]
+    fn wraps_around_the_choices() {
+        let choices = [String::from("yes"), String::from("no")];
+        let mut choice_index = 0;
+
+        assert!(!navigate_choices(
+            Some(Action::Prev),
+            &mut choice_index,
+            &choices
+        ));
+        assert_eq!(choice_index, 1);
+        assert!(!navigate_choices(
+            Some(Action::Next),
+            &mut choice_index,
+            &choices
+        ));
+        assert_eq!(choice_index, 0);
+        assert!(navigate_choices(
+            Some(Action::No),
+            &mut choice_index,
+            &choices
+        ));
+        assert_eq!(choice_index, 1);
+    }
+
+    #[test]
+    fn makes_no_choice_out_of_nothing() {
+        let mut choice_index = 0;
+
+        for action in [Action::Next, Action::Prev, Action::Confirm, Action::Yes] {
+            assert!(!navigate_choices(Some(action), &mut choice_index, &[]));
+            assert_eq!(choice_index, 0);
+        }
+    }
+
+    #[test]
+    fn stacks_the_map_from_30_rows() {
+        assert!(render(60, 30).is_some());
+        assert!(render(60, 29).is_none());
+    }
+}
@@ -0,0 +1,226 @@
+use crossterm::event::{self, Event, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{
+    prelude::{CrosstermBackend, Terminal},
+    Frame,
+};
+use std::error;
+use std::fmt;
+use std::io::{stdout, Error, ErrorKind, Result, Stdout};
+use std::time::Duration;
+
+use crate::app::keymap::{Action, Keymap};
+use crate::app::screens::{ConfirmQuitModal, HelpModal};
+use crate::app::settings::Settings;
+use crate::app::terminal::TerminalGuard;
+use crate::app::theme::Theme;
+use crate::selection::Preferences;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(15);
+
+/**
+ * what the process exits with when the user quits midway, like an interrupted shell command
+ */
+pub const EXIT_CANCELLED: i32 = 130;
+
+pub enum Transition<T> {
+    Stay,
+    Push(Box<dyn Modal>),
+    Finish(T),
+    Cancel,
+}
+
+/**
+ * the user quit, `App::run` fails with it wrapped in an io error
+ */
+#[derive(Debug)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cancelled")
+    }
+}
+
+impl error::Error for Cancelled {}
+
+pub fn is_cancelled(error: &(dyn error::Error + 'static)) -> bool {
+    error
+        .downcast_ref::<Error>()
+        .and_then(|error| error.get_ref())
+        .is_some_and(|error| error.is::<Cancelled>())
+}
+
+/**
+ * a full screen state, the runtime feeds it events and asks it for frames
+ * until it finishes with its output
+ */
+pub trait Screen {
+    type Output;
+
+    fn render(&mut self, frame: &mut Frame, theme: &Theme);
+
+    /*
+     * `action` is what the keymap made of the event, if it's a key with a meaning
+     */
+    fn handle_event(
+        &mut self,
+        _event: &Event,
+        _action: Option<Action>,
+    ) -> Transition<Self::Output> {
+        Transition::Stay
+    }
+
+    /*
+     * called once every loop, for animations and for polling outside state
+     */
+    fn tick(&mut self) -> Transition<Self::Output> {
+        Transition::Stay
+    }
+
+    /*
+     * while typing into the screen keys are text, only the ones held with ctrl still act
+     */
+    fn is_typing(&self) -> bool {
+        false
+    }
+
+    /*
+     * the actions the screen reacts to, listed in the help along with help and quit
+     */
+    fn actions(&self) -> Vec<Action> {
+        vec![]
+    }
+
+    /*
+     * the preferences narrowing this screen down, listed in the help
+     */
+    fn preferences(&self) -> Option<&Preferences> {
+        None
+    }
+
+    /*
+     * called on the quit keys, by default a cycle is in progress so make sure first
+     */
+    fn quit(&mut self, keymap: &Keymap) -> Transition<Self::Output> {
+        Transition::Push(Box::new(ConfirmQuitModal::new(keymap)))
+    }
+}
+
+/**
+ * drawn on top of the running screen, takes all events until it closes
+ */
+pub trait Modal {
+    fn render(&mut self, frame: &mut Frame, theme: &Theme);
+
+    /*
+     * finishing closes the modal, cancelling quits the running screen
+     */
+    fn handle_event(&mut self, event: &Event, action: Option<Action>) -> Transition<()>;
+}
+
+/**
+ * owns the terminal and runs one screen at a time, with a stack of modals above it
+ */
+pub struct App {
+    terminal: Terminal<CrosstermBackend<Stdout>>,
+    modals: Vec<Box<dyn Modal>>,
+    keymap: Keymap,
+    theme: Theme,
+    // dropped last, after the terminal had its chance to show the cursor again
+    _guard: TerminalGuard,
+}
+
+impl App {
+    pub fn new(settings: Settings) -> Result<Self> {
+        let guard = TerminalGuard::enter()?;
+        let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+        terminal.clear()?;
+
+        Ok(App {
+            terminal,
+            modals: vec![],
+            keymap: settings.keymap,
+            theme: settings.theme,
+            _guard: guard,
+        })
+    }
+
+    pub fn keymap(&self) -> &Keymap {
+        &self.keymap
+    }
+
+    pub fn run<S: Screen>(&mut self, screen: &mut S) -> Result<S::Output> {
+        let modals = &mut self.modals;
+        let theme = &self.theme;
+
+        loop {
+            self.terminal.draw(|f| {
+                screen.render(f, theme);
+                for modal in modals.iter_mut() {
+                    modal.render(f, theme);
+                }
+            })?;
+
+            let mut transition = screen.tick();
+
+            if let Transition::Stay = transition {
+                if event::poll(POLL_INTERVAL)? {
+                    let event = event::read()?;
+                    // layouts are worked out from the frame size on every draw,
+                    // the terminal just has to pick up the new size and clear
+                    if let Event::Resize(..) = event {
+                        self.terminal.autoresize()?;
+                    }
+                    let action = key_press(&event)
+                        .filter(|key| {
+                            let typing = modals.is_empty() && screen.is_typing();
+                            !typing || key.modifiers.contains(KeyModifiers::CONTROL)
+                        })
+                        .and_then(|key| self.keymap.action(key));
+
+                    match modals.last_mut() {
+                        Some(modal) => match modal.handle_event(&event, action) {
+                            Transition::Stay => {}
+                            Transition::Push(modal) => modals.push(modal),
+                            Transition::Finish(()) => {
+                                modals.pop();
+                            }
+                            Transition::Cancel => transition = Transition::Cancel,
+                        },
+                        None if action == Some(Action::Quit) => {
+                            transition = screen.quit(&self.keymap)
+                        }
+                        None if action == Some(Action::Help) => {
+                            transition = Transition::Push(Box::new(HelpModal::new(
+                                &self.keymap,
+                                &screen.actions(),
+                                screen.preferences(),
+                            )))
+                        }
+                        None => transition = screen.handle_event(&event, action),
+                    }
+                }
+            }
+
+            match transition {
+                Transition::Stay => {}
+                Transition::Push(modal) => modals.push(modal),
+                Transition::Finish(output) => {
+                    modals.clear();
+                    return Ok(output);
+                }
+                Transition::Cancel => {
+                    modals.clear();
+                    return Err(Error::new(ErrorKind::Interrupted, Cancelled));
+                }
+            }
+        }
+    }
+}
+
+pub fn key_press(event: &Event) -> Option<&KeyEvent> {
+    match event {
+        Event::Key(key) if key.kind == KeyEventKind::Press => Some(key),
+        _ => None,
+    }
+}
@@ -0,0 +1,385 @@
+use crossterm::event::{Event, KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{
+    prelude::{Constraint, Direction, Layout, Rect},
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+use std::cmp::Ordering;
+
+use super::table::DrawnTable;
+use crate::app::keymap::{Action, Keymap};
+use crate::app::runtime::{key_press, Screen, Transition};
+use crate::app::theme::Theme;
+use crate::controllers::WoltAPITypes::ResterauntItem;
+use crate::geo;
+use crate::opening_hours;
+
+const COLUMN_TITLES: [&str; 5] = ["name", "categories", "fee", "eta", "distance"];
+const COLUMN_WIDTHS: [Constraint; 5] = [
+    Constraint::Percentage(30),
+    Constraint::Percentage(40),
+    Constraint::Percentage(10),
+    Constraint::Percentage(10),
+    Constraint::Percentage(10),
+];
+const HIGHLIGHT_SYMBOL: &str = ">> ";
+// the default gap ratatui leaves between table columns
+const COLUMN_SPACING: u16 = 1;
+
+/*
+ * a venue as the table shows it, with the values it sorts by
+ */
+struct Entry {
+    index: usize,
+    title: String,
+    categories: String,
+    fee: String,
+    fee_value: f64,
+    eta: String,
+    eta_minutes: i64,
+    distance_km: f64,
+    // what the search runs over, lowercased
+    haystack: String,
+}
+
+impl Entry {
+    fn new(index: usize, restaurant: &ResterauntItem, address: (f32, f32)) -> Self {
+        let (lat, lon) = address;
+        let location = &restaurant.venue.location;
+        let categories = restaurant.filtering.filters[0].values.join(", ");
+        // "₪10.00" and the like, anything without a number in it is free
+        let fee_value = restaurant
+            .venue
+            .delivery_price
+            .chars()
+            .filter(|char| char.is_ascii_digit() || *char == '.')
+            .collect::<String>()
+            .parse()
+            .unwrap_or(0.0);
+
+        Entry {
+            index,
+            haystack: format!("{} {}", restaurant.title, categories).to_lowercase(),
+            title: restaurant.title.clone(),
+            categories,
+            fee: restaurant.venue.delivery_price.clone(),
+            fee_value,
+            eta: format!("{}min", restaurant.venue.estimate_range),
+            eta_minutes: opening_hours::estimate_max_minutes(&restaurant.venue),
+            distance_km: geo::distance_km((lon.into(), lat.into()), (location[0], location[1])),
+        }
+    }
+
+    fn compare(&self, other: &Entry, column: usize) -> Ordering {
+        match column {
+            0 => self.title.to_lowercase().cmp(&other.title.to_lowercase()),
+            1 => self
+                .categories
+                .to_lowercase()
+                .cmp(&other.categories.to_lowercase()),
+            2 => self.fee_value.total_cmp(&other.fee_value),
+            3 => self.eta_minutes.cmp(&other.eta_minutes),
+            _ => self.distance_km.total_cmp(&other.distance_km),
+        }
+    }
+
+    /*
+     * fuzzy, every character of the query shows up in order, not necessarily next to each other
+     */
+    fn matches(&self, query: &str) -> bool {
+        let mut haystack = self.haystack.chars();
+        query
+            .to_lowercase()
+            .chars()
+            .filter(|char| !char.is_whitespace())
+            .all(|char| haystack.any(|candidate| candidate == char))
+    }
+
+    fn row(&self) -> Row<'static> {
+        Row::new(vec![
+            Cell::from(self.title.clone()),
+            Cell::from(self.categories.clone()),
+            Cell::from(self.fee.clone()),
+            Cell::from(self.eta.clone()),
+            Cell::from(format!("{:.1}km", self.distance_km)),
+        ])
+    }
+}
+
+/**
+ * every venue in the pool in a table, sorted by any column and narrowed down
+ * by a search, finishes with the index of the picked one
+ */
+pub struct BrowseScreen {
+    entries: Vec<Entry>,
+    // the entries that match the search, in the sort order
+    visible: Vec<usize>,
+    sort_column: usize,
+    ascending: bool,
+    query: String,
+    typing: bool,
+    selected: usize,
+    table: DrawnTable,
+    // the keys to show under the table, with and without a search
+    hint: String,
+    search_hint: String,
+}
+
+impl BrowseScreen {
+    pub fn new(restaurants: &[ResterauntItem], address: (f32, f32), keymap: &Keymap) -> Self {
+        let mut screen = BrowseScreen {
+            entries: restaurants
+                .iter()
+                .enumerate()
+                .map(|(index, restaurant)| Entry::new(index, restaurant, address))
+                .collect(),
+            visible: vec![],
+            sort_column: 0,
+            ascending: true,
+            query: String::new(),
+            typing: false,
+            selected: 0,
+            table: DrawnTable::default(),
+            hint: keymap.hint(&[
+                (Action::Search, "search"),
+                (Action::Sort, "sort by the next column"),
+                (Action::ReverseSort, "reverse"),
+                (Action::Confirm, "pick"),
+                (Action::Help, "help"),
+            ]),
+            search_hint: keymap.hint(&[(Action::Search, "to edit"), (Action::Undo, "to clear")]),
+        };
+        screen.refresh();
+
+        screen
+    }
+
+    /*
+     * filters and sorts the entries again, keeping the same venue highlighted if it's still there
+     */
+    fn refresh(&mut self) {
+        let highlighted = self.visible.get(self.selected).copied();
+
+        let entries = &self.entries;
+        self.visible = (0..entries.len())
+            .filter(|index| entries[*index].matches(&self.query))
+            .collect();
+        self.visible.sort_by(|a, b| {
+            let ordering = entries[*a].compare(&entries[*b], self.sort_column);
+            if self.ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+
+        self.selected = highlighted
+            .and_then(|highlighted| self.visible.iter().position(|index| *index == highlighted))
+            .unwrap_or(0);
+    }
+
+    fn sort_by(&mut self, column: usize) {
+        if self.sort_column == column {
+            self.ascending = !self.ascending;
+        } else {
+            self.sort_column = column;
+            self.ascending = true;
+        }
+        self.refresh();
+    }
+
+    fn move_selection(&mut self, by: isize) {
+        let last = self.visible.len().saturating_sub(1);
+        self.selected = self.selected.saturating_add_signed(by).min(last);
+    }
+
+    fn pick(&self) -> Transition<usize> {
+        match self.visible.get(self.selected) {
+            Some(index) => Transition::Finish(self.entries[*index].index),
+            None => Transition::Stay,
+        }
+    }
+
+    fn header(&self) -> Row<'static> {
+        let arrow = if self.ascending { " ▲" } else { " ▼" };
+
+        Row::new(COLUMN_TITLES.iter().enumerate().map(|(column, title)| {
+            if column == self.sort_column {
+                format!("{}{}", title, arrow)
+            } else {
+                title.to_string()
+            }
+        }))
+        .bold()
+    }
+
+    /*
+     * the column under `x`, laid out the way the table lays out its columns
+     */
+    fn column_at(&self, x: u16) -> Option<usize> {
+        // inside the borders, after the room kept for the highlight symbol
+        let area = self.table.area();
+        let inner = Rect {
+            x: area.x + 1,
+            width: area.width.saturating_sub(2),
+            ..area
+        };
+
+        let mut constraints = vec![Constraint::Length(HIGHLIGHT_SYMBOL.chars().count() as u16)];
+        for width in COLUMN_WIDTHS {
+            constraints.push(width);
+            constraints.push(Constraint::Length(COLUMN_SPACING));
+        }
+        constraints.pop();
+
+        Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(constraints)
+            .split(inner)
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .position(|area| x >= area.x && x < area.right())
+    }
+
+    fn handle_mouse(&mut self, event: &Event) -> Transition<usize> {
+        let Event::Mouse(MouseEvent {
+            kind, column, row, ..
+        }) = *event
+        else {
+            return Transition::Stay;
+        };
+
+        match kind {
+            MouseEventKind::ScrollDown => self.move_selection(1),
+            MouseEventKind::ScrollUp => self.move_selection(-1),
+            MouseEventKind::Down(MouseButton::Left) => {
+                if self.table.is_header(row) {
+                    if let Some(column) = self.column_at(column) {
+                        self.sort_by(column);
+                    }
+                } else if let Some(selected) = self.table.row_at(row, self.visible.len()) {
+                    self.selected = selected;
+                    return self.pick();
+                }
+            }
+            _ => {}
+        }
+
+        Transition::Stay
+    }
+
+    fn handle_typing(&mut self, event: &Event) -> Transition<usize> {
+        let Some(key) = key_press(event) else {
+            return Transition::Stay;
+        };
+
+        match key.code {
+            KeyCode::Char(char) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.query.push(char);
+                self.refresh();
+            }
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.refresh();
+            }
+            KeyCode::Esc => self.typing = false,
+            KeyCode::Enter => return self.pick(),
+            KeyCode::Down => self.move_selection(1),
+            KeyCode::Up => self.move_selection(-1),
+            _ => {}
+        }
+
+        Transition::Stay
+    }
+}
+
+impl Screen for BrowseScreen {
+    type Output = usize;
+
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(1)])
+            .split(frame.size());
+
+        let mut table_state = TableState::default().with_selected(Some(self.selected));
+        let table = Table::new(
+            self.visible
+                .iter()
+                .map(|index| self.entries[*index].row())
+                .collect::<Vec<_>>(),
+        )
+        .header(self.header())
+        .widths(&COLUMN_WIDTHS)
+        .column_spacing(COLUMN_SPACING)
+        .highlight_symbol(HIGHLIGHT_SYMBOL)
+        .highlight_style(theme.highlight())
+        .block(Block::default().borders(Borders::ALL).title(format!(
+            "browse: {} of {} venues",
+            self.visible.len(),
+            self.entries.len()
+        )));
+
+        self.table.render(frame, table, layout[0], &mut table_state);
+
+        let search = if self.typing {
+            Line::from(vec![
+                Span::styled(format!("/{}", self.query), theme.text()),
+                Span::styled(" ", theme.highlight()),
+            ])
+        } else if !self.query.is_empty() {
+            Line::from(Span::styled(
+                format!("/{}  ({})", self.query, self.search_hint),
+                theme.text(),
+            ))
+        } else {
+            Line::from(Span::styled(self.hint.clone(), theme.text()))
+        };
+        frame.render_widget(Paragraph::new(search), layout[1]);
+    }
+
+    fn actions(&self) -> Vec<Action> {
+        vec![
+            Action::Search,
+            Action::Sort,
+            Action::ReverseSort,
+            Action::Next,
+            Action::Prev,
+            Action::Undo,
+            Action::Confirm,
+        ]
+    }
+
+    fn is_typing(&self) -> bool {
+        self.typing
+    }
+
+    fn handle_event(&mut self, event: &Event, action: Option<Action>) -> Transition<usize> {
+        if let Event::Mouse(_) = event {
+            return self.handle_mouse(event);
+        }
+
+        if self.typing {
+            return self.handle_typing(event);
+        }
+
+        match action {
+            Some(Action::Search) => self.typing = true,
+            Some(Action::Sort) => self.sort_by((self.sort_column + 1) % COLUMN_TITLES.len()),
+            Some(Action::ReverseSort) => self.sort_by(self.sort_column),
+            Some(Action::Next) => self.move_selection(1),
+            Some(Action::Prev) => self.move_selection(-1),
+            Some(Action::Undo) if !self.query.is_empty() => {
+                self.query.clear();
+                self.refresh();
+            }
+            Some(Action::Confirm) => return self.pick(),
+            _ => {}
+        }
+
+        Transition::Stay
+    }
+}
@@ -0,0 +1,225 @@
+use crossterm::event::{Event, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{
+    prelude::Constraint,
+    style::Stylize,
+    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    Frame,
+};
+
+use super::table::DrawnTable;
+use crate::app::keymap::{Action, Keymap};
+use crate::app::runtime::{Screen, Transition};
+use crate::app::theme::Theme;
+use crate::controllers::WoltAPITypes::ResterauntItem;
+use crate::selection::Preferences;
+
+const COLUMN_WIDTHS: [Constraint; 3] = [
+    Constraint::Length(10),
+    Constraint::Percentage(70),
+    Constraint::Length(8),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stance {
+    Neutral,
+    Liked,
+    Disliked,
+}
+
+/**
+ * every category in the pool with how many venues have it, liked and disliked
+ * ones go into the preferences before the cycle starts
+ */
+pub struct CategoryScreen<'a> {
+    // most common first
+    categories: Vec<(String, usize)>,
+    pool: &'a [ResterauntItem],
+    preferences: &'a mut Preferences,
+    matching_count: usize,
+    choice_index: usize,
+    table: DrawnTable,
+    hint: String,
+}
+
+impl<'a> CategoryScreen<'a> {
+    pub fn new(
+        pool: &'a [ResterauntItem],
+        preferences: &'a mut Preferences,
+        keymap: &Keymap,
+    ) -> Self {
+        let mut categories: Vec<(String, usize)> = vec![];
+        for restaurant in pool {
+            for category in preferences
+                .taxonomy
+                .normalize_all(&restaurant.filtering.filters[0].values)
+            {
+                match categories.iter_mut().find(|(known, _)| *known == category) {
+                    Some((_, count)) => *count += 1,
+                    None => categories.push((category, 1)),
+                }
+            }
+        }
+        categories.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+
+        let matching_count = preferences.filter(pool).len();
+
+        CategoryScreen {
+            categories,
+            pool,
+            preferences,
+            matching_count,
+            choice_index: 0,
+            table: DrawnTable::default(),
+            hint: keymap.hint(&[
+                (Action::Yes, "to like"),
+                (Action::No, "to dislike"),
+                (Action::Confirm, "to start"),
+            ]),
+        }
+    }
+
+    fn stance(&self, category: &str) -> Stance {
+        if self.preferences.liked_category == category {
+            Stance::Liked
+        } else if self
+            .preferences
+            .disliked_categories
+            .iter()
+            .any(|disliked| disliked == category)
+        {
+            Stance::Disliked
+        } else {
+            Stance::Neutral
+        }
+    }
+
+    /*
+     * only one category can be liked, liking another one takes the like away from the last
+     */
+    fn set_stance(&mut self, index: usize, stance: Stance) {
+        let category = self.categories[index].0.clone();
+
+        if self.preferences.liked_category == category {
+            self.preferences.liked_category.clear();
+        }
+        self.preferences
+            .disliked_categories
+            .retain(|disliked| *disliked != category);
+
+        match stance {
+            Stance::Liked => self.preferences.liked_category = category,
+            Stance::Disliked => self.preferences.disliked_categories.push(category),
+            Stance::Neutral => {}
+        }
+
+        self.matching_count = self.preferences.filter(self.pool).len();
+    }
+
+    fn toggle(&mut self, index: usize, stance: Stance) {
+        if self.stance(&self.categories[index].0) == stance {
+            self.set_stance(index, Stance::Neutral);
+        } else {
+            self.set_stance(index, stance);
+        }
+    }
+
+    fn move_selection(&mut self, by: isize) {
+        let last = self.categories.len().saturating_sub(1);
+        self.choice_index = self.choice_index.saturating_add_signed(by).min(last);
+    }
+}
+
+impl Screen for CategoryScreen<'_> {
+    type Output = ();
+
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        let mut table_state = TableState::default().with_selected(Some(self.choice_index));
+
+        let rows: Vec<Row> = self
+            .categories
+            .iter()
+            .map(|(category, count)| {
+                let stance = match self.stance(category) {
+                    Stance::Liked => "liked",
+                    Stance::Disliked => "disliked",
+                    Stance::Neutral => "",
+                };
+
+                Row::new(vec![
+                    Cell::from(stance),
+                    Cell::from(category.clone()),
+                    Cell::from(count.to_string()),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(rows)
+            .header(Row::new(vec!["", "category", "venues"]).bold())
+            .widths(&COLUMN_WIDTHS)
+            .highlight_symbol(">> ")
+            .highlight_style(theme.highlight())
+            .block(Block::default().borders(Borders::ALL).title(format!(
+                "{}: {} of {} venues match",
+                self.hint,
+                self.matching_count,
+                self.pool.len()
+            )));
+
+        self.table
+            .render(frame, table, frame.size(), &mut table_state);
+    }
+
+    fn actions(&self) -> Vec<Action> {
+        vec![
+            Action::Next,
+            Action::Prev,
+            Action::Yes,
+            Action::No,
+            Action::Confirm,
+        ]
+    }
+
+    fn preferences(&self) -> Option<&Preferences> {
+        Some(self.preferences)
+    }
+
+    fn handle_event(&mut self, event: &Event, action: Option<Action>) -> Transition<()> {
+        if let Event::Mouse(MouseEvent { kind, row, .. }) = *event {
+            match kind {
+                MouseEventKind::ScrollDown => self.move_selection(1),
+                MouseEventKind::ScrollUp => self.move_selection(-1),
+                // a click goes from neutral to liked to disliked and back
+                MouseEventKind::Down(MouseButton::Left) => {
+                    if let Some(index) = self.table.row_at(row, self.categories.len()) {
+                        self.choice_index = index;
+                        let stance = match self.stance(&self.categories[index].0) {
+                            Stance::Neutral => Stance::Liked,
+                            Stance::Liked => Stance::Disliked,
+                            Stance::Disliked => Stance::Neutral,
+                        };
+                        self.set_stance(index, stance);
+                    }
+                }
+                _ => {}
+            }
+
+            return Transition::Stay;
+        }
+
+        match action {
+            Some(Action::Next) => self.move_selection(1),
+            Some(Action::Prev) => self.move_selection(-1),
+            Some(Action::Yes) if !self.categories.is_empty() => {
+                self.toggle(self.choice_index, Stance::Liked)
+            }
+            Some(Action::No) if !self.categories.is_empty() => {
+                self.toggle(self.choice_index, Stance::Disliked)
+            }
+            // nothing to pick from with everything ruled out
+            Some(Action::Confirm) if self.matching_count > 0 => return Transition::Finish(()),
+            _ => {}
+        }
+
+        Transition::Stay
+    }
+}
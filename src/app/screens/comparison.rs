@@ -0,0 +1,114 @@
+use crossterm::event::{Event, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::{
+    prelude::Constraint,
+    style::Stylize,
+    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    Frame,
+};
+
+use super::table::DrawnTable;
+use crate::app::keymap::Action;
+use crate::app::restaurant_view::navigate_choices;
+use crate::app::runtime::{Screen, Transition};
+use crate::app::theme::Theme;
+use crate::controllers::WoltAPITypes::ResterauntItem;
+use crate::geo;
+
+const COLUMN_WIDTHS: [Constraint; 5] = [
+    Constraint::Percentage(25),
+    Constraint::Percentage(10),
+    Constraint::Percentage(10),
+    Constraint::Percentage(10),
+    Constraint::Percentage(45),
+];
+
+/**
+ * tabulates the shortlisted restaurants and finishes with the index of the picked one
+ */
+pub struct ComparisonScreen {
+    rows: Vec<Row<'static>>,
+    titles: Vec<String>,
+    choice_index: usize,
+    table: DrawnTable,
+}
+
+impl ComparisonScreen {
+    pub fn new(restaurants: &[ResterauntItem], address: (f32, f32)) -> Self {
+        ComparisonScreen {
+            rows: restaurants
+                .iter()
+                .map(|restaurant| ComparisonScreen::get_comparison_row(restaurant, address))
+                .collect(),
+            titles: restaurants
+                .iter()
+                .map(|restaurant| restaurant.title.clone())
+                .collect(),
+            choice_index: 0,
+            table: DrawnTable::default(),
+        }
+    }
+
+    fn get_comparison_row(restaurant: &ResterauntItem, address: (f32, f32)) -> Row<'static> {
+        let (lat, lon) = address;
+        let location = &restaurant.venue.location;
+        let distance = geo::distance_km((lon.into(), lat.into()), (location[0], location[1]));
+
+        Row::new(vec![
+            Cell::from(restaurant.title.clone()),
+            Cell::from(restaurant.venue.delivery_price.clone()),
+            Cell::from(format!("{}min", restaurant.venue.estimate_range)),
+            Cell::from(format!("{:.1}km", distance)),
+            Cell::from(restaurant.filtering.filters[0].values.join(", ")),
+        ])
+    }
+}
+
+impl Screen for ComparisonScreen {
+    type Output = usize;
+
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        let mut table_state = TableState::default().with_selected(Some(self.choice_index));
+
+        let table = Table::new(self.rows.clone())
+            .header(Row::new(vec!["name", "fee", "eta", "distance", "categories"]).bold())
+            .widths(&COLUMN_WIDTHS)
+            .highlight_symbol(">> ")
+            .highlight_style(theme.highlight())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("which one of the shortlist?"),
+            );
+
+        self.table
+            .render(frame, table, frame.size(), &mut table_state);
+    }
+
+    fn actions(&self) -> Vec<Action> {
+        vec![
+            Action::Next,
+            Action::Prev,
+            Action::Confirm,
+            Action::Choose(0),
+        ]
+    }
+
+    fn handle_event(&mut self, event: &Event, action: Option<Action>) -> Transition<usize> {
+        if let Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            row,
+            ..
+        }) = *event
+        {
+            if let Some(index) = self.table.row_at(row, self.titles.len()) {
+                return Transition::Finish(index);
+            }
+        }
+
+        if navigate_choices(action, &mut self.choice_index, &self.titles) {
+            return Transition::Finish(self.choice_index);
+        }
+
+        Transition::Stay
+    }
+}
@@ -0,0 +1,73 @@
+use crossterm::event::Event;
+use ratatui::{
+    prelude::{Alignment, Rect},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::keymap::{Action, Keymap};
+use crate::app::runtime::{key_press, Modal, Transition};
+use crate::app::theme::Theme;
+
+const CONFIRM_WIDTH: u16 = 40;
+const CONFIRM_HEIGHT: u16 = 5;
+
+/**
+ * asks before throwing away a cycle in progress, yes or quitting again quits
+ */
+pub struct ConfirmQuitModal {
+    hint: String,
+}
+
+impl ConfirmQuitModal {
+    pub fn new(keymap: &Keymap) -> Self {
+        let keys: Vec<String> = [Action::Yes, Action::Quit]
+            .iter()
+            .filter_map(|action| keymap.keys(*action).first().map(|key| key.to_string()))
+            .collect();
+
+        ConfirmQuitModal {
+            hint: format!("{} quits", keys.join(" or ")),
+        }
+    }
+}
+
+impl Modal for ConfirmQuitModal {
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        let screen = frame.size();
+        let width = CONFIRM_WIDTH.min(screen.width);
+        let height = CONFIRM_HEIGHT.min(screen.height);
+        let area = Rect {
+            x: (screen.width - width) / 2,
+            y: (screen.height - height) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(format!(
+                "quit without picking?\n\n{}, any other key keeps going",
+                self.hint
+            ))
+            .alignment(Alignment::Center)
+            .style(theme.text())
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::new()
+                    .borders(Borders::all())
+                    .border_style(theme.warning())
+                    .title("Quit"),
+            ),
+            area,
+        );
+    }
+
+    fn handle_event(&mut self, event: &Event, action: Option<Action>) -> Transition<()> {
+        match action {
+            Some(Action::Yes | Action::Quit) => Transition::Cancel,
+            _ if key_press(event).is_some() => Transition::Finish(()),
+            _ => Transition::Stay,
+        }
+    }
+}
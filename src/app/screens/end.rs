@@ -0,0 +1,236 @@
+use crossterm::event::Event;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use ratatui::{
+    prelude::{Alignment, Rect},
+    style::Style,
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::keymap::{Action, Keymap};
+use crate::app::link;
+use crate::app::runtime::{Screen, Transition};
+use crate::app::theme::Theme;
+
+const MODAL_WIDTH: u16 = 50;
+const MODAL_HEIGHT: u16 = 15;
+
+/**
+ * bounces the result around, with a link to the restaurant it can be opened,
+ * copied or printed once the terminal is back, finishes with the link to print
+ */
+pub struct EndScreen {
+    message: String,
+    link: Option<String>,
+    // the keys for the link, from the keymap
+    link_hint: String,
+    // how the last thing done with the link went
+    status: Option<String>,
+    acc_x: i32,
+    acc_y: i32,
+    rect_x: i32,
+    rect_y: i32,
+    // picks the border color out of the theme's palette
+    border_roll: usize,
+    screen_size: Rect,
+    rng: StdRng,
+}
+
+impl EndScreen {
+    pub fn new(message: String, seed: u64) -> Self {
+        EndScreen {
+            message,
+            link: None,
+            link_hint: String::new(),
+            status: None,
+            acc_x: 1,
+            acc_y: 1,
+            rect_x: 0,
+            rect_y: 0,
+            border_roll: 2,
+            screen_size: Rect::default(),
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn with_link(mut self, link: Option<String>, keymap: &Keymap) -> Self {
+        self.link = link;
+        self.link_hint = keymap.hint(&[
+            (Action::OpenLink, "open"),
+            (Action::CopyLink, "copy"),
+            (Action::PrintLink, "print the link and exit"),
+        ]);
+        self
+    }
+
+    fn text(&self) -> String {
+        let mut text = self.message.clone();
+        if self.link.is_some() {
+            text.push_str("\n\n");
+            text.push_str(&self.link_hint);
+        }
+        if let Some(status) = &self.status {
+            text.push('\n');
+            text.push_str(status);
+        }
+
+        text
+    }
+
+    fn set_random_color(&mut self) {
+        self.border_roll = self.rng.gen();
+    }
+
+    /**
+     * the box shrinks to fit terminals smaller than it
+     */
+    fn get_modal_rect(&self) -> Rect {
+        let width = MODAL_WIDTH.min(self.screen_size.width);
+        let height = MODAL_HEIGHT.min(self.screen_size.height);
+
+        Rect {
+            x: (self.rect_x as u16).min(self.screen_size.width - width),
+            y: (self.rect_y as u16).min(self.screen_size.height - height),
+            width,
+            height,
+        }
+    }
+
+    fn augment_rect(&mut self) {
+        let modal = self.get_modal_rect();
+        let max_x = i32::from(self.screen_size.width - modal.width);
+        let max_y = i32::from(self.screen_size.height - modal.height);
+
+        // after a resize the box may be past the new edges
+        self.rect_x = self.rect_x.min(max_x);
+        self.rect_y = self.rect_y.min(max_y);
+
+        // todo: tidy this monstrosity
+        if self.acc_x > 0 {
+            if self.rect_x >= max_x {
+                self.acc_x *= -1;
+                self.set_random_color();
+            }
+        } else if self.rect_x <= 0 {
+            self.acc_x *= -1;
+            self.set_random_color();
+        }
+
+        if self.acc_y > 0 {
+            if self.rect_y >= max_y {
+                self.acc_y *= -1;
+                self.set_random_color();
+            }
+        } else if self.rect_y <= 0 {
+            self.acc_y *= -1;
+            self.set_random_color();
+        }
+
+        // nowhere to bounce to when the box fills the whole screen
+        self.rect_x = (self.rect_x + self.acc_x).clamp(0, max_x);
+        self.rect_y = (self.rect_y + self.acc_y).clamp(0, max_y);
+    }
+}
+
+impl Screen for EndScreen {
+    type Output = Option<String>;
+
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        self.screen_size = frame.size();
+
+        let rect = self.get_modal_rect();
+        let border_colors = theme.end_borders();
+
+        let p = Paragraph::new(self.text())
+            .alignment(Alignment::Center)
+            .style(theme.banner())
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::new()
+                    .borders(Borders::all())
+                    .border_style(
+                        Style::default().fg(border_colors[self.border_roll % border_colors.len()]),
+                    )
+                    .title("You did it!")
+                    .style(theme.end_background()),
+            );
+        frame.render_widget(p, rect);
+    }
+
+    fn tick(&mut self) -> Transition<Option<String>> {
+        self.augment_rect();
+
+        Transition::Stay
+    }
+
+    fn actions(&self) -> Vec<Action> {
+        let mut actions = vec![Action::Confirm];
+        if self.link.is_some() {
+            actions.extend([Action::OpenLink, Action::CopyLink, Action::PrintLink]);
+        }
+
+        actions
+    }
+
+    // nothing left to cancel, quitting just closes it
+    fn quit(&mut self, _keymap: &Keymap) -> Transition<Option<String>> {
+        Transition::Finish(None)
+    }
+
+    fn handle_event(
+        &mut self,
+        _event: &Event,
+        action: Option<Action>,
+    ) -> Transition<Option<String>> {
+        if action == Some(Action::Confirm) {
+            return Transition::Finish(None);
+        }
+
+        let Some(link) = &self.link else {
+            return Transition::Stay;
+        };
+
+        match action {
+            Some(Action::OpenLink) => {
+                self.status = Some(match link::open(link) {
+                    Ok(()) => String::from("opened in the browser"),
+                    Err(error) => format!("couldn't open the link: {}", error),
+                });
+            }
+            Some(Action::CopyLink) => {
+                self.status = Some(match link::copy(link) {
+                    Ok(()) => String::from("copied, if the terminal allows OSC 52"),
+                    Err(error) => format!("couldn't copy the link: {}", error),
+                });
+            }
+            Some(Action::PrintLink) => return Transition::Finish(Some(link.clone())),
+            _ => {}
+        }
+
+        Transition::Stay
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    #[test]
+    fn shrinks_the_box_to_a_smaller_terminal() {
+        let (width, height) = (MODAL_WIDTH - 20, MODAL_HEIGHT - 5);
+        let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+        let mut screen = EndScreen::new(String::from("Falafel it is!"), 0);
+
+        // a few frames of bouncing, with nowhere to bounce to
+        for _ in 0..5 {
+            terminal.draw(|f| screen.render(f, &Theme::dark())).unwrap();
+            screen.tick();
+        }
+
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer.get(0, 0).symbol, "┌");
+        assert_eq!(buffer.get(width - 1, height - 1).symbol, "┘");
+    }
+}
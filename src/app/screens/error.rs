@@ -0,0 +1,62 @@
+use crossterm::event::Event;
+use ratatui::{
+    prelude::{Alignment, Rect},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::keymap::Action;
+use crate::app::runtime::{key_press, Modal, Transition};
+use crate::app::theme::Theme;
+
+const ERROR_WIDTH: u16 = 50;
+const ERROR_HEIGHT: u16 = 7;
+
+/**
+ * tells about something that went wrong without ending the session, any key closes it
+ */
+pub struct ErrorModal {
+    message: String,
+}
+
+impl ErrorModal {
+    pub fn new(message: String) -> Self {
+        ErrorModal { message }
+    }
+}
+
+impl Modal for ErrorModal {
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        let screen = frame.size();
+        let width = ERROR_WIDTH.min(screen.width);
+        let height = ERROR_HEIGHT.min(screen.height);
+        let area = Rect {
+            x: (screen.width - width) / 2,
+            y: (screen.height - height) / 2,
+            width,
+            height,
+        };
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(format!("{}\n\npress any key", self.message))
+                .alignment(Alignment::Center)
+                .style(theme.text())
+                .wrap(Wrap { trim: false })
+                .block(
+                    Block::new()
+                        .borders(Borders::all())
+                        .border_style(theme.error())
+                        .title("Something went wrong"),
+                ),
+            area,
+        );
+    }
+
+    fn handle_event(&mut self, event: &Event, _action: Option<Action>) -> Transition<()> {
+        match key_press(event) {
+            Some(_) => Transition::Finish(()),
+            None => Transition::Stay,
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use crossterm::event::{Event, MouseEvent, MouseEventKind};
+use ratatui::{
+    prelude::Rect,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::keymap::{Action, Keymap};
+use crate::app::runtime::{key_press, Modal, Transition};
+use crate::app::theme::Theme;
+use crate::selection::Preferences;
+
+const HELP_WIDTH: u16 = 64;
+
+/**
+ * the keys of what the screen underneath handles, straight from the keymap,
+ * and what the pool is filtered by. scrolls when it doesn't fit
+ */
+pub struct HelpModal {
+    lines: Vec<Line<'static>>,
+    scroll: u16,
+    // the keys that scroll, for the title when there's more than fits
+    scroll_keys: String,
+}
+
+impl HelpModal {
+    pub fn new(keymap: &Keymap, actions: &[Action], preferences: Option<&Preferences>) -> Self {
+        let mut lines = vec![Line::from("keys".bold())];
+
+        // help and quit are handled before any screen sees them
+        for action in actions.iter().chain(&[Action::Help, Action::Quit]) {
+            let keys = match action {
+                Action::Choose(_) => String::from("1-9"),
+                action => HelpModal::keys(keymap, *action),
+            };
+            if keys.is_empty() {
+                continue;
+            }
+
+            lines.push(HelpModal::entry(keys, action.description()));
+        }
+
+        if let Some(preferences) = preferences {
+            lines.push(Line::from(""));
+            lines.push(Line::from("filters".bold()));
+            lines.extend(
+                preferences
+                    .summary()
+                    .into_iter()
+                    .map(|line| Line::from(format!("  {}", line))),
+            );
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from("press any other key to close".italic()));
+
+        HelpModal {
+            lines,
+            scroll: 0,
+            scroll_keys: format!(
+                "{} {}",
+                HelpModal::keys(keymap, Action::Prev),
+                HelpModal::keys(keymap, Action::Next)
+            ),
+        }
+    }
+
+    fn keys(keymap: &Keymap, action: Action) -> String {
+        keymap
+            .keys(action)
+            .iter()
+            .map(|key| key.to_string())
+            .collect::<Vec<_>>()
+            .join(" / ")
+    }
+
+    fn entry(keys: String, description: &str) -> Line<'static> {
+        Line::from(vec![
+            Span::styled(format!("  {:<20}", keys), Style::new().bold()),
+            Span::raw(description.to_string()),
+        ])
+    }
+}
+
+impl Modal for HelpModal {
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        let screen = frame.size();
+        let width = HELP_WIDTH.min(screen.width);
+        // the lines and the borders
+        let height = (self.lines.len() as u16 + 2).min(screen.height);
+        let area = Rect {
+            x: (screen.width - width) / 2,
+            y: (screen.height - height) / 2,
+            width,
+            height,
+        };
+
+        let max_scroll = (self.lines.len() as u16).saturating_sub(height.saturating_sub(2));
+        self.scroll = self.scroll.min(max_scroll);
+        let title = if max_scroll > 0 {
+            format!("Help, {} to scroll", self.scroll_keys)
+        } else {
+            String::from("Help")
+        };
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(self.lines.clone())
+                .style(theme.text())
+                .scroll((self.scroll, 0))
+                .block(Block::new().borders(Borders::all()).title(title)),
+            area,
+        );
+    }
+
+    fn handle_event(&mut self, event: &Event, action: Option<Action>) -> Transition<()> {
+        if let Event::Mouse(MouseEvent { kind, .. }) = event {
+            match kind {
+                MouseEventKind::ScrollDown => self.scroll = self.scroll.saturating_add(1),
+                MouseEventKind::ScrollUp => self.scroll = self.scroll.saturating_sub(1),
+                _ => {}
+            }
+
+            return Transition::Stay;
+        }
+
+        if key_press(event).is_none() {
+            return Transition::Stay;
+        }
+
+        match action {
+            Some(Action::Next) => self.scroll = self.scroll.saturating_add(1),
+            Some(Action::Prev) => self.scroll = self.scroll.saturating_sub(1),
+            _ => return Transition::Finish(()),
+        }
+
+        Transition::Stay
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use super::*;
+    use crate::app::restaurant_view::MAP_ACTIONS;
+
+    fn text(help: &HelpModal) -> String {
+        help.lines
+            .iter()
+            .flat_map(|line| line.spans.iter().map(|span| span.content.to_string()))
+            .collect()
+    }
+
+    fn screen_text(terminal: &Terminal<TestBackend>) -> String {
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content
+            .iter()
+            .map(|cell| cell.symbol.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn lists_only_what_the_screen_handles() {
+        let keymap = Keymap::new(&HashMap::new()).unwrap();
+        let help = HelpModal::new(&keymap, &[Action::Search], None);
+
+        let text = text(&help);
+        assert!(text.contains(Action::Search.description()));
+        assert!(text.contains(Action::Quit.description()));
+        assert!(!text.contains(Action::ZoomIn.description()));
+    }
+
+    #[test]
+    fn scrolls_to_the_bottom_on_a_small_terminal() {
+        let keymap = Keymap::new(&HashMap::new()).unwrap();
+        let mut actions = vec![
+            Action::Next,
+            Action::Prev,
+            Action::Confirm,
+            Action::Choose(0),
+        ];
+        actions.extend([Action::Yes, Action::No, Action::Favorite, Action::Block]);
+        actions.extend(MAP_ACTIONS);
+        let preferences = Preferences::default();
+        let mut help = HelpModal::new(&keymap, &actions, Some(&preferences));
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        let theme = Theme::dark();
+        terminal.draw(|f| help.render(f, &theme)).unwrap();
+        assert!(screen_text(&terminal).contains("to scroll"));
+        assert!(!screen_text(&terminal).contains("press any other key to close"));
+
+        let down = Event::Key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        for _ in 0..help.lines.len() {
+            let action = keymap.action(key_press(&down).unwrap());
+            assert!(matches!(help.handle_event(&down, action), Transition::Stay));
+        }
+        terminal.draw(|f| help.render(f, &theme)).unwrap();
+        assert!(screen_text(&terminal).contains("press any other key to close"));
+    }
+}
@@ -0,0 +1,129 @@
+use crossterm::event::Event;
+use ratatui::{
+    prelude::{Constraint, Direction, Layout},
+    Frame,
+};
+
+use crate::app::keymap::Action;
+use crate::app::restaurant_view::{navigate_choices, RestaurantView, MAP_ACTIONS, MATCHUP_LABELS};
+use crate::app::runtime::{Screen, Transition};
+use crate::app::theme::Theme;
+use crate::controllers::WoltAPITypes::ResterauntItem;
+use crate::selection::Preferences;
+
+/**
+ * asks to pick one of two restaurants, both are shown next to each other and on the map
+ */
+pub struct MatchupScreen<'a> {
+    question: String,
+    restaurants: [&'a ResterauntItem; 2],
+    choices: Vec<String>,
+    choice_index: usize,
+    view: &'a mut RestaurantView,
+    preferences: &'a Preferences,
+}
+
+impl<'a> MatchupScreen<'a> {
+    pub fn new(
+        question: &str,
+        restaurants: [&'a ResterauntItem; 2],
+        view: &'a mut RestaurantView,
+        preferences: &'a Preferences,
+    ) -> Self {
+        view.reset_zoom();
+
+        MatchupScreen {
+            question: question.to_string(),
+            restaurants,
+            choices: restaurants
+                .map(|restaurant| restaurant.title.clone())
+                .to_vec(),
+            choice_index: 0,
+            view,
+            preferences,
+        }
+    }
+}
+
+impl Screen for MatchupScreen<'_> {
+    type Output = usize;
+
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        let (address_coordinates, first_coordinates) =
+            self.view.get_coordinates(self.restaurants[0]);
+        let (_, second_coordinates) = self.view.get_coordinates(self.restaurants[1]);
+        let restaurants_coordinates = [first_coordinates, second_coordinates];
+
+        let layout = self.view.get_restaurant_display(frame.size());
+
+        let top_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(0)])
+            .split(layout.top);
+        let descriptions_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(top_layout[1]);
+
+        RestaurantView::render_top_section(frame, theme, self.question.clone(), top_layout[0]);
+        for (index, restaurant) in self.restaurants.iter().enumerate() {
+            let description = format!(
+                "{}: {}",
+                MATCHUP_LABELS[index],
+                RestaurantView::get_restaurant_description(self.preferences, restaurant)
+            );
+            RestaurantView::render_top_section(
+                frame,
+                theme,
+                description,
+                descriptions_layout[index],
+            );
+        }
+        self.view.render_choices(
+            frame,
+            theme,
+            &self.choices,
+            self.choice_index,
+            layout.choices,
+        );
+        if let Some(map_area) = layout.map {
+            self.view.render_map(
+                frame,
+                theme,
+                address_coordinates,
+                &restaurants_coordinates,
+                map_area,
+            );
+        }
+    }
+
+    fn actions(&self) -> Vec<Action> {
+        let mut actions = vec![
+            Action::Next,
+            Action::Prev,
+            Action::Confirm,
+            Action::Choose(0),
+        ];
+        actions.extend(MAP_ACTIONS);
+
+        actions
+    }
+
+    fn preferences(&self) -> Option<&Preferences> {
+        Some(self.preferences)
+    }
+
+    fn handle_event(&mut self, event: &Event, action: Option<Action>) -> Transition<usize> {
+        if let Some(choice_index) = self.view.handle_mouse(event, self.choices.len()) {
+            return Transition::Finish(choice_index);
+        }
+
+        if !self.view.handle_map_action(action)
+            && navigate_choices(action, &mut self.choice_index, &self.choices)
+        {
+            return Transition::Finish(self.choice_index);
+        }
+
+        Transition::Stay
+    }
+}
@@ -0,0 +1,24 @@
+mod browse;
+mod categories;
+mod comparison;
+mod confirm_quit;
+mod end;
+mod error;
+mod help;
+mod matchup;
+mod question;
+mod swipe;
+mod table;
+mod waiting;
+
+pub use browse::BrowseScreen;
+pub use categories::CategoryScreen;
+pub use comparison::ComparisonScreen;
+pub use confirm_quit::ConfirmQuitModal;
+pub use end::EndScreen;
+pub use error::ErrorModal;
+pub use help::HelpModal;
+pub use matchup::MatchupScreen;
+pub use question::QuestionScreen;
+pub use swipe::{SwipeChoice, SwipeScreen};
+pub use waiting::WaitingScreen;
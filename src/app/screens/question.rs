@@ -0,0 +1,152 @@
+use crossterm::event::Event;
+use ratatui::Frame;
+
+use super::ErrorModal;
+use crate::app::keymap::Action;
+use crate::app::restaurant_view::{navigate_choices, RestaurantView, MAP_ACTIONS};
+use crate::app::runtime::{Screen, Transition};
+use crate::app::theme::Theme;
+use crate::controllers::WoltAPITypes::ResterauntItem;
+use crate::selection::Preferences;
+
+/**
+ * asks a question about a restaurant, finishing with the chosen index
+ */
+pub struct QuestionScreen<'a> {
+    question: String,
+    restaurant: &'a ResterauntItem,
+    choices: Vec<String>,
+    choice_index: usize,
+    view: &'a mut RestaurantView,
+    preferences: &'a mut Preferences,
+    show_restaurant: bool,
+    block_choice_index: Option<usize>,
+}
+
+impl<'a> QuestionScreen<'a> {
+    pub fn new(
+        question: &str,
+        restaurant: &'a ResterauntItem,
+        choices: Vec<String>,
+        view: &'a mut RestaurantView,
+        preferences: &'a mut Preferences,
+    ) -> Self {
+        QuestionScreen {
+            question: question.to_string(),
+            restaurant,
+            choices,
+            choice_index: 0,
+            view,
+            preferences,
+            show_restaurant: false,
+            block_choice_index: None,
+        }
+    }
+
+    /**
+     * describes the restaurant under the question and lets the user toggle it as a
+     * favorite or block it, which answers with `block_choice_index`
+     */
+    pub fn about_restaurant(mut self, block_choice_index: usize) -> Self {
+        self.show_restaurant = true;
+        self.block_choice_index = Some(block_choice_index);
+        self
+    }
+
+    fn mark_venue(&mut self, action: Option<Action>) -> Transition<usize> {
+        let Some(block_choice_index) = self.block_choice_index else {
+            return Transition::Stay;
+        };
+
+        let slug = &self.restaurant.venue.slug;
+        let venue_lists = &mut self.preferences.venue_lists;
+        let finished = match action {
+            Some(Action::Favorite) => {
+                venue_lists.toggle_favorite(slug);
+                false
+            }
+            Some(Action::Block) => {
+                venue_lists.block(slug);
+                true
+            }
+            _ => return Transition::Stay,
+        };
+
+        if let Err(error) = venue_lists.save() {
+            return Transition::Push(Box::new(ErrorModal::new(format!(
+                "failed to save the venue lists: {}",
+                error
+            ))));
+        }
+
+        // blocking is a firm no
+        if finished {
+            Transition::Finish(block_choice_index)
+        } else {
+            Transition::Stay
+        }
+    }
+}
+
+impl Screen for QuestionScreen<'_> {
+    type Output = usize;
+
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        let (address_coordinates, restaurant_coordinates) =
+            self.view.get_coordinates(self.restaurant);
+
+        let top_section_text = if self.show_restaurant {
+            let restaurant_description =
+                RestaurantView::get_restaurant_description(self.preferences, self.restaurant);
+            format!("{} \n {}", self.question, restaurant_description)
+        } else {
+            self.question.clone()
+        };
+
+        self.view.render_resteraunt_display(
+            frame,
+            theme,
+            top_section_text,
+            &self.choices,
+            self.choice_index,
+            (address_coordinates, restaurant_coordinates),
+        );
+    }
+
+    fn actions(&self) -> Vec<Action> {
+        let mut actions = vec![
+            Action::Next,
+            Action::Prev,
+            Action::Confirm,
+            Action::Choose(0),
+            Action::Yes,
+            Action::No,
+        ];
+        if self.block_choice_index.is_some() {
+            actions.extend([Action::Favorite, Action::Block]);
+        }
+        actions.extend(MAP_ACTIONS);
+
+        actions
+    }
+
+    fn preferences(&self) -> Option<&Preferences> {
+        Some(self.preferences)
+    }
+
+    fn handle_event(&mut self, event: &Event, action: Option<Action>) -> Transition<usize> {
+        if let Some(choice_index) = self.view.handle_mouse(event, self.choices.len()) {
+            return Transition::Finish(choice_index);
+        }
+
+        if self.view.handle_map_action(action) {
+            return Transition::Stay;
+        }
+
+        if navigate_choices(action, &mut self.choice_index, &self.choices) {
+            return Transition::Finish(self.choice_index);
+        }
+
+        self.mark_venue(action)
+    }
+}
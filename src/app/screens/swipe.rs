@@ -0,0 +1,144 @@
+use crossterm::event::Event;
+
+use crate::app::keymap::{Action, Keymap};
+use ratatui::Frame;
+
+use crate::app::restaurant_view::{RestaurantView, MAP_ACTIONS};
+use crate::app::runtime::{Screen, Transition};
+use crate::app::theme::Theme;
+use crate::controllers::WoltAPITypes::ResterauntItem;
+use crate::selection::Preferences;
+
+pub enum SwipeChoice {
+    Skip,
+    Shortlist,
+    Done,
+    Undo,
+}
+
+/**
+ * swipe left or no to skip the restaurant, right or yes to shortlist it,
+ * enter to stop swiping and undo to take back the last swipe
+ */
+pub struct SwipeScreen<'a> {
+    restaurant: &'a ResterauntItem,
+    remaining_count: usize,
+    shortlisted_count: usize,
+    hints: Vec<String>,
+    view: &'a mut RestaurantView,
+    preferences: &'a Preferences,
+}
+
+impl<'a> SwipeScreen<'a> {
+    pub fn new(
+        restaurant: &'a ResterauntItem,
+        shortlisted_count: usize,
+        remaining_count: usize,
+        view: &'a mut RestaurantView,
+        preferences: &'a Preferences,
+        keymap: &Keymap,
+    ) -> Self {
+        view.reset_zoom();
+
+        // the hints double as buttons, so they keep their text even without a key
+        let hint = |action: Action, what: &str| match keymap.hint(&[(action, what)]) {
+            hint if hint.is_empty() => what.to_string(),
+            hint => hint,
+        };
+
+        SwipeScreen {
+            restaurant,
+            remaining_count,
+            shortlisted_count,
+            hints: vec![
+                hint(Action::SwipeLeft, "skip"),
+                hint(Action::SwipeRight, "shortlist"),
+                if shortlisted_count > 0 {
+                    hint(
+                        Action::Confirm,
+                        &format!("compare {} shortlisted", shortlisted_count),
+                    )
+                } else {
+                    String::from("shortlist something to compare")
+                },
+            ],
+            view,
+            preferences,
+        }
+    }
+
+    /*
+     * there's nothing to compare until something is shortlisted
+     */
+    fn done(&self) -> Transition<SwipeChoice> {
+        if self.shortlisted_count == 0 {
+            return Transition::Stay;
+        }
+
+        Transition::Finish(SwipeChoice::Done)
+    }
+}
+
+impl Screen for SwipeScreen<'_> {
+    type Output = SwipeChoice;
+
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        let (address_coordinates, restaurant_coordinates) =
+            self.view.get_coordinates(self.restaurant);
+
+        let top_section_text = format!(
+            "{} left to swipe \n {}",
+            self.remaining_count,
+            RestaurantView::get_restaurant_description(self.preferences, self.restaurant)
+        );
+
+        self.view.render_resteraunt_display(
+            frame,
+            theme,
+            top_section_text,
+            &self.hints,
+            usize::MAX, // nothing to highlight, these are just hints
+            (address_coordinates, restaurant_coordinates),
+        );
+    }
+
+    fn actions(&self) -> Vec<Action> {
+        let mut actions = vec![
+            Action::SwipeLeft,
+            Action::SwipeRight,
+            Action::Yes,
+            Action::No,
+            Action::Confirm,
+            Action::Undo,
+        ];
+        actions.extend(MAP_ACTIONS);
+
+        actions
+    }
+
+    fn preferences(&self) -> Option<&Preferences> {
+        Some(self.preferences)
+    }
+
+    fn handle_event(&mut self, event: &Event, action: Option<Action>) -> Transition<SwipeChoice> {
+        // the hints double as buttons
+        match self.view.handle_mouse(event, self.hints.len()) {
+            Some(0) => return Transition::Finish(SwipeChoice::Skip),
+            Some(1) => return Transition::Finish(SwipeChoice::Shortlist),
+            Some(_) => return self.done(),
+            None => {}
+        }
+
+        if self.view.handle_map_action(action) {
+            return Transition::Stay;
+        }
+
+        match action {
+            Some(Action::No | Action::SwipeLeft) => Transition::Finish(SwipeChoice::Skip),
+            Some(Action::Yes | Action::SwipeRight) => Transition::Finish(SwipeChoice::Shortlist),
+            Some(Action::Confirm) => self.done(),
+            Some(Action::Undo) => Transition::Finish(SwipeChoice::Undo),
+            _ => Transition::Stay,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use ratatui::{
+    prelude::Rect,
+    widgets::{Table, TableState},
+    Frame,
+};
+
+/**
+ * where a bordered table with a header was last drawn and how far it was scrolled,
+ * for the mouse to hit its rows
+ */
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DrawnTable {
+    area: Rect,
+    offset: usize,
+}
+
+impl DrawnTable {
+    pub fn render(&mut self, frame: &mut Frame, table: Table, area: Rect, state: &mut TableState) {
+        frame.render_stateful_widget(table, area, state);
+        self.area = area;
+        self.offset = state.offset();
+    }
+
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    pub fn is_header(&self, row: u16) -> bool {
+        row == self.area.y + 1
+    }
+
+    /**
+     * the index of the row drawn on the terminal row `row` out of `len` rows,
+     * none on the borders, the header or below the last row
+     */
+    pub fn row_at(&self, row: u16, len: usize) -> Option<usize> {
+        if row + 1 >= self.area.bottom() {
+            return None;
+        }
+
+        // the border and the header come before the first row
+        let index = row.checked_sub(self.area.y + 2)?;
+        Some(usize::from(index) + self.offset).filter(|index| *index < len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hits_the_rows_between_the_header_and_the_border() {
+        let table = DrawnTable {
+            area: Rect::new(0, 3, 40, 10),
+            offset: 5,
+        };
+
+        assert_eq!(table.row_at(3, 20), None);
+        assert!(table.is_header(4));
+        assert_eq!(table.row_at(4, 20), None);
+        assert_eq!(table.row_at(5, 20), Some(5));
+        assert_eq!(table.row_at(11, 20), Some(11));
+        assert_eq!(table.row_at(12, 20), None);
+        assert_eq!(table.row_at(7, 7), None);
+    }
+}
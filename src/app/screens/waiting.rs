@@ -0,0 +1,37 @@
+use ratatui::Frame;
+
+use crate::app::restaurant_view::RestaurantView;
+use crate::app::runtime::{Screen, Transition};
+use crate::app::theme::Theme;
+
+/**
+ * shows a message until `poll` comes back with something, `poll` may update the message
+ */
+pub struct WaitingScreen<F> {
+    message: String,
+    poll: F,
+}
+
+impl<F> WaitingScreen<F> {
+    pub fn new(message: &str, poll: F) -> Self {
+        WaitingScreen {
+            message: message.to_string(),
+            poll,
+        }
+    }
+}
+
+impl<T, F: FnMut(&mut String) -> Option<T>> Screen for WaitingScreen<F> {
+    type Output = T;
+
+    fn render(&mut self, frame: &mut Frame, theme: &Theme) {
+        RestaurantView::render_top_section(frame, theme, self.message.clone(), frame.size());
+    }
+
+    fn tick(&mut self) -> Transition<T> {
+        match (self.poll)(&mut self.message) {
+            Some(output) => Transition::Finish(output),
+            None => Transition::Stay,
+        }
+    }
+}
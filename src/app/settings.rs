@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::app::keymap::Keymap;
+use crate::app::theme::{Theme, DEFAULT_THEME};
+use crate::config::{config_dir, Config};
+use crate::geo::Basemap;
+
+/**
+ * how the ui looks and reacts, worked out from config.json up front
+ * so mistakes in it are reported before the terminal is taken over
+ */
+pub struct Settings {
+    pub keymap: Keymap,
+    pub theme: Theme,
+    pub basemap: Option<Arc<Basemap>>,
+}
+
+impl Settings {
+    pub fn new(config: &Config) -> Result<Self, String> {
+        Ok(Settings {
+            keymap: Keymap::new(&config.keys)?,
+            theme: Theme::load(
+                config.theme.as_deref().unwrap_or(DEFAULT_THEME),
+                &config.themes,
+            )?,
+            basemap: None,
+        })
+    }
+
+    /**
+     * loads the base map config.json points at, it takes a while on a big extract
+     * so only the modes drawing a map ask for it
+     */
+    pub fn with_basemap(mut self, config: &Config) -> Result<Self, String> {
+        if let Some(path) = &config.basemap {
+            let path = match config_dir() {
+                Some(dir) if path.is_relative() => dir.join(path),
+                _ => path.clone(),
+            };
+            self.basemap = Some(Arc::new(Basemap::load(&path)?));
+        }
+
+        Ok(self)
+    }
+}
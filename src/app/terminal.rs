@@ -0,0 +1,64 @@
+use crossterm::{
+    cursor::Show,
+    event::{DisableMouseCapture, EnableMouseCapture},
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    ExecutableCommand,
+};
+use std::io::{stdout, Result};
+use std::panic;
+use std::sync::Once;
+
+static PANIC_HOOK: Once = Once::new();
+
+/**
+ * raw mode, the alternate screen and the mouse for as long as it lives,
+ * the user's shell gets restored on drop and on panic
+ */
+pub struct TerminalGuard;
+
+impl TerminalGuard {
+    pub fn enter() -> Result<Self> {
+        TerminalGuard::install_panic_hook();
+
+        enable_raw_mode()?;
+        if let Err(error) = stdout()
+            .execute(EnterAlternateScreen)
+            .and_then(|stdout| stdout.execute(EnableMouseCapture))
+        {
+            let _ = disable_raw_mode();
+            return Err(error);
+        }
+
+        Ok(TerminalGuard)
+    }
+
+    /*
+     * restores the terminal before the default hook prints the panic,
+     * otherwise the message is lost in the alternate screen
+     */
+    fn install_panic_hook() {
+        PANIC_HOOK.call_once(|| {
+            let default_hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                let _ = TerminalGuard::restore();
+                default_hook(info);
+            }));
+        });
+    }
+
+    fn restore() -> Result<()> {
+        disable_raw_mode()?;
+        stdout()
+            .execute(DisableMouseCapture)?
+            .execute(LeaveAlternateScreen)?
+            .execute(Show)?;
+
+        Ok(())
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = TerminalGuard::restore();
+    }
+}
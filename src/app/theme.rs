@@ -0,0 +1,354 @@
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
+use std::env;
+use std::str::FromStr;
+
+pub const DEFAULT_THEME: &str = "dark";
+
+/**
+ * every color the ui draws with, with `no_color` only modifiers are used
+ */
+#[derive(Debug, Clone)]
+pub struct Theme {
+    banner_fg: Color,
+    banner_bg: Color,
+    highlight_fg: Color,
+    highlight_bg: Color,
+    text: Color,
+    map: Color,
+    route: Color,
+    home: Color,
+    streets: Color,
+    landmarks: Color,
+    markers: Vec<Color>,
+    candidate: Color,
+    filtered_out: Color,
+    error: Color,
+    warning: Color,
+    end_background: Color,
+    end_borders: Vec<Color>,
+    no_color: bool,
+}
+
+/**
+ * a user theme from config.json, on top of one of the built in ones
+ */
+#[derive(Debug, Default, Deserialize)]
+pub struct ThemeOverrides {
+    #[serde(default)]
+    base: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    banner_fg: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    banner_bg: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    highlight_fg: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    highlight_bg: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    text: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    map: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    route: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    home: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    streets: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    landmarks: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_colors")]
+    markers: Option<Vec<Color>>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    candidate: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    filtered_out: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    error: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    warning: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_color")]
+    end_background: Option<Color>,
+    #[serde(default, deserialize_with = "deserialize_colors")]
+    end_borders: Option<Vec<Color>>,
+}
+
+fn parse_color<E: serde::de::Error>(value: &str) -> Result<Color, E> {
+    Color::from_str(value).map_err(|_| E::custom(format!("unknown color: {}", value)))
+}
+
+fn deserialize_color<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Color>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    parse_color(&value).map(Some)
+}
+
+fn deserialize_colors<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Vec<Color>>, D::Error> {
+    let values = Vec::<String>::deserialize(deserializer)?;
+    if values.is_empty() {
+        return Err(serde::de::Error::custom("expected at least one color"));
+    }
+
+    values
+        .iter()
+        .map(|value| parse_color(value))
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Theme {
+            banner_fg: Color::White,
+            banner_bg: Color::LightBlue,
+            highlight_fg: Color::Black,
+            highlight_bg: Color::LightYellow,
+            text: Color::White,
+            map: Color::Cyan,
+            route: Color::Green,
+            home: Color::White,
+            streets: Color::DarkGray,
+            landmarks: Color::Blue,
+            markers: vec![Color::Magenta, Color::Yellow],
+            candidate: Color::Gray,
+            filtered_out: Color::DarkGray,
+            error: Color::Red,
+            warning: Color::Yellow,
+            end_background: Color::Black,
+            end_borders: vec![
+                Color::Red,
+                Color::Blue,
+                Color::Magenta,
+                Color::Cyan,
+                Color::Yellow,
+                Color::White,
+                Color::Green,
+            ],
+            no_color: false,
+        }
+    }
+
+    pub fn light() -> Self {
+        Theme {
+            banner_fg: Color::White,
+            banner_bg: Color::Blue,
+            highlight_fg: Color::White,
+            highlight_bg: Color::Magenta,
+            text: Color::Black,
+            map: Color::Blue,
+            route: Color::Green,
+            home: Color::Black,
+            streets: Color::Gray,
+            landmarks: Color::LightBlue,
+            markers: vec![Color::Magenta, Color::Red],
+            candidate: Color::DarkGray,
+            filtered_out: Color::Gray,
+            error: Color::Red,
+            warning: Color::Magenta,
+            end_background: Color::White,
+            end_borders: vec![
+                Color::Red,
+                Color::Blue,
+                Color::Magenta,
+                Color::Green,
+                Color::Black,
+            ],
+            no_color: false,
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Theme {
+            banner_fg: Color::Black,
+            banner_bg: Color::White,
+            highlight_fg: Color::Black,
+            highlight_bg: Color::LightYellow,
+            text: Color::White,
+            map: Color::White,
+            route: Color::LightGreen,
+            home: Color::LightYellow,
+            streets: Color::Gray,
+            landmarks: Color::White,
+            markers: vec![Color::LightMagenta, Color::LightCyan],
+            candidate: Color::White,
+            filtered_out: Color::Gray,
+            error: Color::LightRed,
+            warning: Color::LightYellow,
+            end_background: Color::Black,
+            end_borders: vec![Color::White, Color::LightYellow, Color::LightCyan],
+            no_color: false,
+        }
+    }
+
+    fn built_in(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Theme::dark()),
+            "light" => Some(Theme::light()),
+            "high-contrast" => Some(Theme::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /**
+     * the named theme, user themes first, and without colors when NO_COLOR is set
+     */
+    pub fn load(name: &str, user_themes: &HashMap<String, ThemeOverrides>) -> Result<Self, String> {
+        let mut theme = match user_themes.get(name) {
+            Some(overrides) => {
+                let base = overrides.base.as_deref().unwrap_or(DEFAULT_THEME);
+                let theme = Theme::built_in(base)
+                    .ok_or_else(|| format!("unknown base theme for {}: {}", name, base))?;
+
+                theme.with_overrides(overrides)
+            }
+            None => Theme::built_in(name).ok_or_else(|| format!("unknown theme: {}", name))?,
+        };
+
+        // https://no-color.org
+        theme.no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+
+        Ok(theme)
+    }
+
+    fn with_overrides(mut self, overrides: &ThemeOverrides) -> Self {
+        let colors = [
+            (&mut self.banner_fg, overrides.banner_fg),
+            (&mut self.banner_bg, overrides.banner_bg),
+            (&mut self.highlight_fg, overrides.highlight_fg),
+            (&mut self.highlight_bg, overrides.highlight_bg),
+            (&mut self.text, overrides.text),
+            (&mut self.map, overrides.map),
+            (&mut self.route, overrides.route),
+            (&mut self.home, overrides.home),
+            (&mut self.streets, overrides.streets),
+            (&mut self.landmarks, overrides.landmarks),
+            (&mut self.candidate, overrides.candidate),
+            (&mut self.filtered_out, overrides.filtered_out),
+            (&mut self.error, overrides.error),
+            (&mut self.warning, overrides.warning),
+            (&mut self.end_background, overrides.end_background),
+        ];
+        for (color, override_color) in colors {
+            if let Some(override_color) = override_color {
+                *color = override_color;
+            }
+        }
+
+        if let Some(markers) = &overrides.markers {
+            self.markers = markers.clone();
+        }
+        if let Some(end_borders) = &overrides.end_borders {
+            self.end_borders = end_borders.clone();
+        }
+
+        self
+    }
+
+    /**
+     * whether colors are off, what colors would tell apart needs another way then
+     */
+    pub fn no_color(&self) -> bool {
+        self.no_color
+    }
+
+    #[cfg(test)]
+    pub fn without_colors(mut self) -> Self {
+        self.no_color = true;
+        self
+    }
+
+    fn color(&self, color: Color) -> Color {
+        if self.no_color {
+            Color::Reset
+        } else {
+            color
+        }
+    }
+
+    /**
+     * the question and restaurant text, and the end screen message
+     */
+    pub fn banner(&self) -> Style {
+        if self.no_color {
+            return Style::new().add_modifier(Modifier::BOLD);
+        }
+
+        Style::new().fg(self.banner_fg).bg(self.banner_bg)
+    }
+
+    pub fn highlight(&self) -> Style {
+        if self.no_color {
+            return Style::new().add_modifier(Modifier::REVERSED);
+        }
+
+        Style::new().fg(self.highlight_fg).bg(self.highlight_bg)
+    }
+
+    pub fn text(&self) -> Style {
+        Style::new().fg(self.color(self.text))
+    }
+
+    pub fn error(&self) -> Style {
+        Style::new().fg(self.color(self.error))
+    }
+
+    pub fn warning(&self) -> Style {
+        Style::new().fg(self.color(self.warning))
+    }
+
+    pub fn end_background(&self) -> Style {
+        Style::new().bg(self.color(self.end_background))
+    }
+
+    pub fn end_borders(&self) -> Vec<Color> {
+        self.end_borders
+            .iter()
+            .map(|color| self.color(*color))
+            .collect()
+    }
+
+    pub fn map(&self) -> Color {
+        self.color(self.map)
+    }
+
+    pub fn route(&self) -> Color {
+        self.color(self.route)
+    }
+
+    pub fn home(&self) -> Color {
+        self.color(self.home)
+    }
+
+    /**
+     * the base map, its streets and the outlines and names of everything else
+     */
+    pub fn streets(&self) -> Color {
+        self.color(self.streets)
+    }
+
+    pub fn landmarks(&self) -> Color {
+        self.color(self.landmarks)
+    }
+
+    /**
+     * the other venues on the map, ones that match the filters and ones that don't
+     */
+    pub fn candidate(&self) -> Color {
+        self.color(self.candidate)
+    }
+
+    pub fn filtered_out(&self) -> Color {
+        self.color(self.filtered_out)
+    }
+
+    /**
+     * tells apart the restaurants on the map, in the order of the choices
+     */
+    pub fn marker(&self, index: usize) -> Color {
+        self.color(self.markers[index % self.markers.len()])
+    }
+}
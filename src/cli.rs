@@ -0,0 +1,246 @@
+use crate::dietary;
+use crate::headless::OutputFormat;
+use crate::opening_hours::Availability;
+use crate::selection::Preferences;
+
+pub const USAGE: &str = "usage:
+    woltcli                    pick a restaurant on this machine
+    woltcli bracket [--size N] pick by knocking restaurants out in pairs
+    woltcli swipe              shortlist restaurants and compare them
+    woltcli browse             pick straight from a table of every restaurant
+    woltcli pick [--format json|text] [--like CATEGORY] [--dislike CATEGORY]...
+                               print a pick without the ui, for scripts
+                               exits 1 if fetching failed, 3 if nothing matched
+    woltcli favorites [list | add SLUG | remove SLUG]
+                               venues that come up more often
+    woltcli blocked [list | add SLUG | remove SLUG]
+                               venues that never come up
+    woltcli host [--port N]    host a voting session on the local network
+    woltcli join <host:port>   join a voting session hosted by someone else
+
+options:
+    --seed N                   replay the random choices of an earlier session
+    --diet vegan,kosher,...    only consider restaurants fitting these diets
+                               (vegan, kosher, gluten-free)
+    --at HH:MM                 only consider restaurants that can deliver by then
+    --include-closed           also consider restaurants that are closed right now
+    --categories               like and dislike categories on an overview before starting
+
+keys:
+    up/k, down/j and enter pick a choice, 1-9 and y/n answer straight away
+    +/- zoom the map, H/J/K/L pan it, r resets it and c centers it on the restaurant
+    when swiping left/n skips, right/y shortlists and u undoes the last swipe
+    ? lists the keys of the screen you're on
+    when browsing / searches, s and S sort, backspace clears the search
+    on the end screen o opens the restaurant, Y copies its link and p prints it on exit
+    esc, q or ctrl-c quits the ui midway, exiting with 130
+    the mouse works too: click a choice, scroll to zoom and drag to pan the map
+    all of them can be rebound under \"keys\" in config.json
+
+themes:
+    dark, light or high-contrast under \"theme\" in config.json, or your own
+    from \"themes\", set NO_COLOR to draw without any colors
+
+basemap:
+    streets and landmarks under the map from a GeoJSON export of OpenStreetMap,
+    its path under \"basemap\" in config.json, PBF extracts need converting first
+    with it the map shows the walk to the restaurant along the streets and how long it takes";
+
+const DEFAULT_PORT: u16 = 7878;
+const DEFAULT_BRACKET_SIZE: usize = 8;
+
+pub enum VenueList {
+    Favorites,
+    Blocked,
+}
+
+pub enum VenueListAction {
+    List,
+    Add(String),
+    Remove(String),
+}
+
+pub enum Mode {
+    Local,
+    Bracket {
+        size: usize,
+    },
+    Swipe,
+    Browse,
+    Pick {
+        format: OutputFormat,
+    },
+    VenueList {
+        list: VenueList,
+        action: VenueListAction,
+    },
+    Host {
+        port: u16,
+    },
+    Join {
+        address: String,
+    },
+}
+
+pub struct Cli {
+    pub mode: Mode,
+    pub seed: Option<u64>,
+    pub preferences: Preferences,
+    pub category_overview: bool,
+}
+
+impl Cli {
+    pub fn parse() -> Result<Self, String> {
+        Cli::parse_from(std::env::args().skip(1).collect())
+    }
+
+    fn parse_from(args: Vec<String>) -> Result<Self, String> {
+        // global options may appear anywhere, pull them out before the command
+        let mut seed = None;
+        let mut category_overview = false;
+        let mut preferences = Preferences::default();
+        let mut command_args = vec![];
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--seed" {
+                let value = args.next().ok_or("--seed expects a value")?;
+                seed = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("invalid seed: {}", value))?,
+                );
+            } else if arg == "--diet" {
+                let value = args.next().ok_or("--diet expects a list of diets")?;
+                preferences.dietary_profiles = dietary::parse_profiles(&value)?;
+            } else if arg == "--at" {
+                let value = args.next().ok_or("--at expects a time")?;
+                preferences.availability = Availability::parse_order_time(&value)?;
+            } else if arg == "--include-closed" {
+                preferences.availability = Availability::Any;
+            } else if arg == "--categories" {
+                category_overview = true;
+            } else {
+                command_args.push(arg);
+            }
+        }
+
+        let mut args = command_args.into_iter();
+
+        let mode = match args.next().as_deref() {
+            None => Mode::Local,
+            Some("bracket") => {
+                let mut size = DEFAULT_BRACKET_SIZE;
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--size" => {
+                            let value = args.next().ok_or("--size expects a value")?;
+                            size = value
+                                .parse()
+                                .ok()
+                                .filter(|size| *size >= 2)
+                                .ok_or(format!("invalid bracket size: {}", value))?;
+                        }
+                        _ => return Err(format!("unexpected argument: {}", arg)),
+                    }
+                }
+
+                Mode::Bracket { size }
+            }
+            Some("swipe") => {
+                if let Some(arg) = args.next() {
+                    return Err(format!("unexpected argument: {}", arg));
+                }
+
+                Mode::Swipe
+            }
+            Some("browse") => {
+                if let Some(arg) = args.next() {
+                    return Err(format!("unexpected argument: {}", arg));
+                }
+
+                Mode::Browse
+            }
+            Some("pick") => {
+                let mut format = OutputFormat::Json;
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--format" => {
+                            format = match args.next().as_deref() {
+                                Some("json") => OutputFormat::Json,
+                                Some("text") => OutputFormat::Text,
+                                _ => return Err(String::from("--format expects json or text")),
+                            };
+                        }
+                        "--like" => {
+                            preferences.liked_category =
+                                args.next().ok_or("--like expects a category")?;
+                        }
+                        "--dislike" => {
+                            preferences
+                                .disliked_categories
+                                .push(args.next().ok_or("--dislike expects a category")?);
+                        }
+                        _ => return Err(format!("unexpected argument: {}", arg)),
+                    }
+                }
+
+                Mode::Pick { format }
+            }
+            Some(command @ ("favorites" | "blocked")) => {
+                let list = if command == "favorites" {
+                    VenueList::Favorites
+                } else {
+                    VenueList::Blocked
+                };
+
+                let action = match args.next().as_deref() {
+                    None | Some("list") => VenueListAction::List,
+                    Some("add") => {
+                        VenueListAction::Add(args.next().ok_or("add expects a venue slug")?)
+                    }
+                    Some("remove") => {
+                        VenueListAction::Remove(args.next().ok_or("remove expects a venue slug")?)
+                    }
+                    Some(arg) => return Err(format!("unexpected argument: {}", arg)),
+                };
+                if let Some(arg) = args.next() {
+                    return Err(format!("unexpected argument: {}", arg));
+                }
+
+                Mode::VenueList { list, action }
+            }
+            Some("host") => {
+                let mut port = DEFAULT_PORT;
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--port" => {
+                            let value = args.next().ok_or("--port expects a value")?;
+                            port = value
+                                .parse()
+                                .map_err(|_| format!("invalid port: {}", value))?;
+                        }
+                        _ => return Err(format!("unexpected argument: {}", arg)),
+                    }
+                }
+
+                Mode::Host { port }
+            }
+            Some("join") => {
+                let address = args.next().ok_or("join expects a host address")?;
+                if let Some(arg) = args.next() {
+                    return Err(format!("unexpected argument: {}", arg));
+                }
+
+                Mode::Join { address }
+            }
+            Some(arg) => return Err(format!("unknown command: {}", arg)),
+        };
+
+        Ok(Cli {
+            mode,
+            seed,
+            preferences,
+            category_overview,
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use crate::app::{Action, ThemeOverrides};
+
+const CONFIG_FILE_NAME: &str = "config.json";
+
+/**
+ * where the user editable files live, `$XDG_CONFIG_HOME/woltcli` or `~/.config/woltcli`
+ */
+pub fn config_dir() -> Option<PathBuf> {
+    let base = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(xdg_config_home) if !xdg_config_home.is_empty() => PathBuf::from(xdg_config_home),
+        _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+    };
+
+    Some(base.join("woltcli"))
+}
+
+/**
+ * the user's config.json, `keys` rebinds actions: { "keys": { "next": ["down", "l"] } },
+ * `theme` names a built in or one of the user's `themes`: { "mine": { "base": "light", "map": "#3377aa" } },
+ * `basemap` is a GeoJSON of the city's streets, relative to the config dir unless absolute
+ */
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub keys: HashMap<Action, Vec<String>>,
+    #[serde(default)]
+    pub theme: Option<String>,
+    #[serde(default)]
+    pub themes: HashMap<String, ThemeOverrides>,
+    #[serde(default)]
+    pub basemap: Option<PathBuf>,
+}
+
+impl Config {
+    pub fn load() -> Result<Self, String> {
+        let Some(path) = config_dir().map(|dir| dir.join(CONFIG_FILE_NAME)) else {
+            return Ok(Config::default());
+        };
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|error| format!("invalid {}: {}", path.display(), error)),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Config::default()),
+            Err(error) => Err(format!("failed to read {}: {}", path.display(), error)),
+        }
+    }
+}
@@ -1,5 +1,3 @@
-use reqwest;
-
 pub mod types;
 
 pub struct WoltAPI {
@@ -22,11 +20,9 @@ impl WoltAPI {
             .get("https://consumer-api.wolt.com/v1/pages/venue-list/lunch-venues")
             .query(&[("lat", self.lat), ("lon", self.lon)])
             .send()
-            .await
-            .unwrap()
+            .await?
             .json::<types::GetAllRestaurantsResponse>()
-            .await
-            .unwrap();
+            .await?;
 
         Ok(resp)
     }
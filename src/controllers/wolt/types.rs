@@ -1,31 +1,53 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ResterauntLink {
     pub target: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ResterauntFilter {
     pub id: String,
     pub values: Vec<String>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ResterauntFiltering {
     pub filters: Vec<ResterauntFilter>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ResterauntTimeValue {
+    #[serde(rename = "$date")]
+    pub millis_since_midnight: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ResterauntOpeningTime {
+    #[serde(rename = "type")]
+    pub kind: String, // "open" or "close"
+    pub value: ResterauntTimeValue,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ResterauntVenue {
     pub address: String,
     pub estimate_range: String,
     pub location: Vec<f64>,
     pub delivery_price: String,
     pub slug: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub online: Option<bool>,
+    #[serde(default)]
+    pub delivers: Option<bool>,
+    #[serde(default)]
+    pub opening_times: Option<HashMap<String, Vec<ResterauntOpeningTime>>>, // keyed by lowercase weekday
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ResterauntItem {
     pub link: ResterauntLink,
     pub title: String,
@@ -33,14 +55,67 @@ pub struct ResterauntItem {
     pub venue: ResterauntVenue,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+impl ResterauntItem {
+    pub fn wolt_url(&self) -> String {
+        format!(
+            "https://wolt.com/en/isr/tel-aviv/restaurant/{}",
+            self.venue.slug
+        )
+    }
+
+    /**
+     * a venue with just enough filled in for tests
+     */
+    #[cfg(test)]
+    pub fn sample(slug: &str, categories: &[&str]) -> Self {
+        ResterauntItem {
+            link: ResterauntLink {
+                target: slug.to_string(),
+            },
+            title: slug.to_string(),
+            filtering: ResterauntFiltering {
+                filters: vec![ResterauntFilter {
+                    id: "primary".to_string(),
+                    values: categories
+                        .iter()
+                        .map(|category| category.to_string())
+                        .collect(),
+                }],
+            },
+            venue: ResterauntVenue {
+                address: String::new(),
+                estimate_range: "20-30".to_string(),
+                location: vec![34.8114, 32.0796],
+                delivery_price: "₪10.00".to_string(),
+                slug: slug.to_string(),
+                tags: vec![],
+                online: None,
+                delivers: None,
+                opening_times: None,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct ResterauntSection {
     pub items: Vec<ResterauntItem>,
     pub name: String,
     pub template: String,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct GetAllRestaurantsResponse {
     pub sections: Vec<ResterauntSection>,
 }
+
+impl GetAllRestaurantsResponse {
+    /**
+     * the venues are all in the first section, an empty response has none
+     */
+    pub fn items(&self) -> &[ResterauntItem] {
+        self.sections
+            .first()
+            .map_or(&[], |section| section.items.as_slice())
+    }
+}
@@ -0,0 +1,97 @@
+use crate::controllers::WoltAPITypes::ResterauntItem;
+
+/**
+ * hard dietary restrictions, a restaurant that doesn't fit one of the active
+ * profiles never makes it into the pool
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DietaryProfile {
+    Vegan,
+    Kosher,
+    GlutenFree,
+}
+
+impl DietaryProfile {
+    pub const ALL: [DietaryProfile; 3] = [
+        DietaryProfile::Vegan,
+        DietaryProfile::Kosher,
+        DietaryProfile::GlutenFree,
+    ];
+
+    pub fn parse(name: &str) -> Option<Self> {
+        DietaryProfile::ALL
+            .into_iter()
+            .find(|profile| profile.name() == name.trim().to_lowercase())
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DietaryProfile::Vegan => "vegan",
+            DietaryProfile::Kosher => "kosher",
+            DietaryProfile::GlutenFree => "gluten-free",
+        }
+    }
+
+    /*
+     * tags or categories that tell us the restaurant caters for the profile
+     */
+    fn markers(&self) -> &'static [&'static str] {
+        match self {
+            DietaryProfile::Vegan => &["vegan"],
+            DietaryProfile::Kosher => &["kosher"],
+            DietaryProfile::GlutenFree => &["gluten-free", "gluten free", "gluten_free"],
+        }
+    }
+
+    fn labels(item: &ResterauntItem) -> impl Iterator<Item = String> + '_ {
+        item.filtering.filters[0]
+            .values
+            .iter()
+            .chain(item.venue.tags.iter())
+            .map(|label| label.to_lowercase())
+    }
+
+    pub fn is_marked(&self, item: &ResterauntItem) -> bool {
+        DietaryProfile::labels(item).any(|label| self.markers().contains(&label.as_str()))
+    }
+
+    /**
+     * none of the diets can be told from the categories alone, pizza and desserts
+     * look as vegan as a salad bar does, so only a marker counts
+     */
+    pub fn allows(&self, item: &ResterauntItem) -> bool {
+        self.is_marked(item)
+    }
+}
+
+pub fn parse_profiles(names: &str) -> Result<Vec<DietaryProfile>, String> {
+    names
+        .split(',')
+        .map(|name| {
+            DietaryProfile::parse(name).ok_or(format!(
+                "unknown diet: {} (expected one of {})",
+                name,
+                DietaryProfile::ALL.map(|profile| profile.name()).join(", ")
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_lets_marked_venues_through() {
+        let salad_bar = ResterauntItem::sample("salad-bar", &["salad", "vegan"]);
+        let pizza = ResterauntItem::sample("pizza-corner", &["pizza"]);
+        let mut cafe = ResterauntItem::sample("corner-cafe", &["cafe", "dessert"]);
+        cafe.venue.tags.push(String::from("Gluten Free"));
+
+        assert!(DietaryProfile::Vegan.allows(&salad_bar));
+        assert!(!DietaryProfile::Vegan.allows(&pizza));
+        assert!(!DietaryProfile::Vegan.allows(&cafe));
+        assert!(DietaryProfile::GlutenFree.allows(&cafe));
+        assert!(!DietaryProfile::Kosher.allows(&salad_bar));
+    }
+}
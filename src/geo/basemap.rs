@@ -0,0 +1,457 @@
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::path::Path;
+
+use super::walking::{Route, StreetGraph};
+
+// roads no one walks along, unless tagged otherwise
+const CAR_ONLY_HIGHWAYS: [&str; 4] = ["motorway", "motorway_link", "trunk", "trunk_link"];
+
+// GeoJSON positions are [longitude, latitude, maybe altitude]
+type Position = Vec<f64>;
+
+// zoom levels whose projection is kept around, the zoom animation goes through a few
+const CACHED_LEVELS: usize = 8;
+
+#[derive(Deserialize)]
+struct FeatureCollection {
+    features: Vec<Feature>,
+}
+
+#[derive(Deserialize)]
+struct Feature {
+    geometry: Option<Geometry>,
+    #[serde(default)]
+    properties: Option<HashMap<String, Value>>,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+enum Geometry {
+    Point {
+        coordinates: Position,
+    },
+    LineString {
+        coordinates: Vec<Position>,
+    },
+    MultiLineString {
+        coordinates: Vec<Vec<Position>>,
+    },
+    Polygon {
+        coordinates: Vec<Vec<Position>>,
+    },
+    MultiPolygon {
+        coordinates: Vec<Vec<Vec<Position>>>,
+    },
+    #[serde(other)]
+    Other,
+}
+
+/**
+ * a line of the base map, in (lon, lat) as the map canvas takes its x and y
+ */
+#[derive(Debug, Clone)]
+pub struct Way {
+    pub points: Vec<(f64, f64)>,
+    // anything tagged `highway`, the rest are outlines of buildings, parks, water and such
+    pub street: bool,
+    // streets that can be walked along, for the walking routes
+    pub walkable: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Landmark {
+    pub coordinates: (f64, f64),
+    pub name: String,
+}
+
+/**
+ * the streets and landmarks of the city, from a GeoJSON export of OpenStreetMap
+ */
+#[derive(Debug, Default)]
+pub struct Basemap {
+    pub ways: Vec<Way>,
+    pub landmarks: Vec<Landmark>,
+    walking: StreetGraph,
+}
+
+/*
+ * the base map projected at one zoom level, a power of two cells per degree across and up,
+ * over the area around where the map was when it got projected
+ */
+#[derive(Debug)]
+struct Projection {
+    level: (i32, i32),
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+    // counted from longitude 0 eastwards and from latitude 0 southwards
+    streets: Vec<(i64, i64)>,
+    outlines: Vec<(i64, i64)>,
+}
+
+impl Projection {
+    fn covers(&self, x_bounds: [f64; 2], y_bounds: [f64; 2]) -> bool {
+        self.x_bounds[0] <= x_bounds[0]
+            && x_bounds[1] <= self.x_bounds[1]
+            && self.y_bounds[0] <= y_bounds[0]
+            && y_bounds[1] <= self.y_bounds[1]
+    }
+}
+
+/**
+ * the base map drawn into the canvas grid, out of projections cached by zoom level,
+ * so panning and zooming through levels seen before don't walk the ways again
+ */
+#[derive(Debug, Default)]
+pub struct Raster {
+    projections: VecDeque<Projection>,
+    // what the grid was last drawn for
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+    resolution: (usize, usize),
+    pub streets: Vec<(usize, usize)>,
+    pub outlines: Vec<(usize, usize)>,
+}
+
+impl Raster {
+    /**
+     * draws the base map into a grid of `resolution` points spanning the bounds,
+     * with the same projection the canvas uses
+     */
+    pub fn update(
+        &mut self,
+        basemap: &Basemap,
+        x_bounds: [f64; 2],
+        y_bounds: [f64; 2],
+        resolution: (usize, usize),
+    ) {
+        if self.x_bounds == x_bounds && self.y_bounds == y_bounds && self.resolution == resolution {
+            return;
+        }
+        self.x_bounds = x_bounds;
+        self.y_bounds = y_bounds;
+        self.resolution = resolution;
+        self.streets.clear();
+        self.outlines.clear();
+
+        let (width, height) = (x_bounds[1] - x_bounds[0], y_bounds[1] - y_bounds[0]);
+        if width <= 0.0 || height <= 0.0 || resolution.0 < 2 || resolution.1 < 2 {
+            return;
+        }
+        let scale = (
+            (resolution.0 - 1) as f64 / width,
+            (resolution.1 - 1) as f64 / height,
+        );
+
+        // the closest level at least as fine as the grid, so lines stay unbroken
+        let level = (scale.0.log2().ceil() as i32, scale.1.log2().ceil() as i32);
+        let cached = self.projections.iter().position(|projection| {
+            projection.level == level && projection.covers(x_bounds, y_bounds)
+        });
+        let projection = match cached {
+            Some(index) => &self.projections[index],
+            None => {
+                self.projections
+                    .retain(|projection| projection.level != level);
+                if self.projections.len() == CACHED_LEVELS {
+                    self.projections.pop_front();
+                }
+
+                // a map's worth around it on every side, to pan into
+                self.projections.push_back(basemap.project(
+                    level,
+                    [x_bounds[0] - width, x_bounds[1] + width],
+                    [y_bounds[0] - height, y_bounds[1] + height],
+                ));
+                &self.projections[self.projections.len() - 1]
+            }
+        };
+
+        let level_scale = (2f64.powi(level.0), 2f64.powi(level.1));
+        for (cells, grid) in [
+            (&projection.streets, &mut self.streets),
+            (&projection.outlines, &mut self.outlines),
+        ] {
+            for (x, y) in cells {
+                // from the middle of the projected cell to the point it lands on in the grid
+                let lon = (*x as f64 + 0.5) / level_scale.0;
+                let lat = -(*y as f64 + 0.5) / level_scale.1;
+                let grid_x = (lon - x_bounds[0]) * scale.0;
+                let grid_y = (y_bounds[1] - lat) * scale.1;
+
+                if (0.0..resolution.0 as f64).contains(&grid_x)
+                    && (0.0..resolution.1 as f64).contains(&grid_y)
+                {
+                    grid.push((grid_x as usize, grid_y as usize));
+                }
+            }
+
+            grid.sort_unstable();
+            grid.dedup();
+        }
+    }
+}
+
+fn to_point(position: &Position) -> Option<(f64, f64)> {
+    match position.as_slice() {
+        [lon, lat, ..] => Some((*lon, *lat)),
+        _ => None,
+    }
+}
+
+fn to_points(positions: &[Position]) -> Vec<(f64, f64)> {
+    positions.iter().filter_map(to_point).collect()
+}
+
+/*
+ * cuts the segment down to the part inside the bounds, Liang-Barsky
+ */
+fn clip(
+    from: (f64, f64),
+    to: (f64, f64),
+    x_bounds: [f64; 2],
+    y_bounds: [f64; 2],
+) -> Option<((f64, f64), (f64, f64))> {
+    let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+    let (mut enter, mut exit) = (0.0_f64, 1.0_f64);
+
+    for (p, q) in [
+        (-dx, from.0 - x_bounds[0]),
+        (dx, x_bounds[1] - from.0),
+        (-dy, from.1 - y_bounds[0]),
+        (dy, y_bounds[1] - from.1),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+            continue;
+        }
+
+        let t = q / p;
+        if p < 0.0 {
+            enter = enter.max(t);
+        } else {
+            exit = exit.min(t);
+        }
+        if enter > exit {
+            return None;
+        }
+    }
+
+    Some((
+        (from.0 + enter * dx, from.1 + enter * dy),
+        (from.0 + exit * dx, from.1 + exit * dy),
+    ))
+}
+
+impl Basemap {
+    /**
+     * reads a GeoJSON FeatureCollection, OSM PBF extracts have to be exported to GeoJSON first
+     */
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("pbf"))
+        {
+            return Err(format!(
+                "{} is an OSM PBF extract, export it to GeoJSON first, e.g. `osmium export {} -o city.geojson`",
+                path.display(),
+                path.display()
+            ));
+        }
+
+        let contents = fs::read_to_string(path)
+            .map_err(|error| format!("failed to read {}: {}", path.display(), error))?;
+        let collection: FeatureCollection = serde_json::from_str(&contents)
+            .map_err(|error| format!("invalid {}: {}", path.display(), error))?;
+
+        Ok(Basemap::from_features(collection.features))
+    }
+
+    fn from_features(features: Vec<Feature>) -> Self {
+        let mut basemap = Basemap::default();
+
+        for feature in features {
+            let properties = feature.properties.unwrap_or_default();
+            let highway = properties.get("highway").and_then(Value::as_str);
+            let street = highway.is_some();
+            let walkable = highway.is_some_and(|highway| !CAR_ONLY_HIGHWAYS.contains(&highway))
+                && properties.get("foot").and_then(Value::as_str) != Some("no");
+            let mut add_way = |points: Vec<(f64, f64)>| {
+                if points.len() > 1 {
+                    basemap.ways.push(Way {
+                        points,
+                        street,
+                        walkable,
+                    });
+                }
+            };
+
+            match feature.geometry {
+                Some(Geometry::Point { coordinates }) => {
+                    let name = properties.get("name").and_then(Value::as_str);
+                    if let (Some(coordinates), Some(name)) = (to_point(&coordinates), name) {
+                        basemap.landmarks.push(Landmark {
+                            coordinates,
+                            name: name.to_string(),
+                        });
+                    }
+                }
+                Some(Geometry::LineString { coordinates }) => add_way(to_points(&coordinates)),
+                Some(Geometry::MultiLineString { coordinates })
+                | Some(Geometry::Polygon { coordinates }) => {
+                    for line in coordinates {
+                        add_way(to_points(&line));
+                    }
+                }
+                Some(Geometry::MultiPolygon { coordinates }) => {
+                    for ring in coordinates.into_iter().flatten() {
+                        add_way(to_points(&ring));
+                    }
+                }
+                Some(Geometry::Other) | None => {}
+            }
+        }
+
+        basemap.walking = StreetGraph::new(&basemap.ways);
+
+        basemap
+    }
+
+    /**
+     * the shortest walk along the streets between two (lon, lat) points
+     */
+    pub fn walking_route(&self, from: (f64, f64), to: (f64, f64)) -> Option<Route> {
+        self.walking.route(from, to)
+    }
+
+    /*
+     * every cell the ways pass through at the level, only the parts of them within the bounds
+     */
+    fn project(&self, level: (i32, i32), x_bounds: [f64; 2], y_bounds: [f64; 2]) -> Projection {
+        let mut projection = Projection {
+            level,
+            x_bounds,
+            y_bounds,
+            streets: vec![],
+            outlines: vec![],
+        };
+        let scale = (2f64.powi(level.0), 2f64.powi(level.1));
+
+        for way in &self.ways {
+            let cells = if way.street {
+                &mut projection.streets
+            } else {
+                &mut projection.outlines
+            };
+
+            for segment in way.points.windows(2) {
+                let Some((from, to)) = clip(segment[0], segment[1], x_bounds, y_bounds) else {
+                    continue;
+                };
+                let (from, to) = (
+                    (from.0 * scale.0, -from.1 * scale.1),
+                    (to.0 * scale.0, -to.1 * scale.1),
+                );
+
+                let steps = (to.0 - from.0)
+                    .abs()
+                    .max((to.1 - from.1).abs())
+                    .ceil()
+                    .max(1.0);
+                for step in 0..=steps as usize {
+                    let t = step as f64 / steps;
+                    cells.push((
+                        (from.0 + (to.0 - from.0) * t).floor() as i64,
+                        (from.1 + (to.1 - from.1) * t).floor() as i64,
+                    ));
+                }
+            }
+        }
+
+        for cells in [&mut projection.streets, &mut projection.outlines] {
+            cells.sort_unstable();
+            cells.dedup();
+        }
+
+        projection
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a street running east through the default address, as a GeoJSON export has it
+    const STREET: &str = r#"{
+        "type": "FeatureCollection",
+        "features": [
+            {
+                "type": "Feature",
+                "properties": { "highway": "residential" },
+                "geometry": {
+                    "type": "LineString",
+                    "coordinates": [[34.80, 32.0796], [34.82, 32.0796]]
+                }
+            },
+            {
+                "type": "Feature",
+                "properties": { "name": "Park" },
+                "geometry": { "type": "Point", "coordinates": [34.81, 32.08] }
+            }
+        ]
+    }"#;
+
+    fn basemap() -> Basemap {
+        let collection: FeatureCollection = serde_json::from_str(STREET).unwrap();
+        Basemap::from_features(collection.features)
+    }
+
+    #[test]
+    fn keeps_positions_in_map_order() {
+        let basemap = basemap();
+
+        assert_eq!(basemap.ways[0].points[0], (34.80, 32.0796));
+        assert!(basemap.ways[0].street);
+        assert_eq!(basemap.landmarks[0].coordinates, (34.81, 32.08));
+    }
+
+    #[test]
+    fn rasterizes_a_street_inside_the_bounds() {
+        let mut raster = Raster::default();
+        // 0.04 degrees across over 41 points, 0.02 up over 21, a point every 0.001
+        raster.update(&basemap(), [34.79, 34.83], [32.07, 32.09], (41, 21));
+
+        assert!(raster.outlines.is_empty());
+        assert!(!raster.streets.is_empty());
+        // 0.0104 degrees below the top edge
+        assert!(raster.streets.iter().all(|(_, y)| (9..=11).contains(y)));
+        let columns: Vec<usize> = raster.streets.iter().map(|(x, _)| *x).collect();
+        assert!((9..=11).contains(columns.iter().min().unwrap()));
+        assert!((29..=31).contains(columns.iter().max().unwrap()));
+    }
+
+    #[test]
+    fn leaves_out_what_is_off_the_map() {
+        let mut raster = Raster::default();
+        raster.update(&basemap(), [34.90, 34.94], [32.07, 32.09], (41, 21));
+
+        assert!(raster.streets.is_empty());
+    }
+
+    #[test]
+    fn reuses_the_projection_while_panning() {
+        let basemap = basemap();
+        let mut raster = Raster::default();
+        raster.update(&basemap, [34.79, 34.83], [32.07, 32.09], (41, 21));
+        raster.update(&basemap, [34.80, 34.84], [32.07, 32.09], (41, 21));
+
+        assert_eq!(raster.projections.len(), 1);
+        let columns: Vec<usize> = raster.streets.iter().map(|(x, _)| *x).collect();
+        assert!(*columns.iter().min().unwrap() <= 1);
+        assert!((19..=21).contains(columns.iter().max().unwrap()));
+    }
+}
@@ -0,0 +1,21 @@
+mod basemap;
+pub use basemap::{Basemap, Raster};
+
+mod walking;
+pub use walking::Route;
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/**
+ * great-circle distance between two points in kilometers, every point in here is
+ * (lon, lat) like in GeoJSON and on the map canvas
+ */
+pub fn distance_km(from: (f64, f64), to: (f64, f64)) -> f64 {
+    let (from_lon, from_lat) = (from.0.to_radians(), from.1.to_radians());
+    let (to_lon, to_lat) = (to.0.to_radians(), to.1.to_radians());
+
+    let a = ((to_lat - from_lat) / 2.0).sin().powi(2)
+        + from_lat.cos() * to_lat.cos() * ((to_lon - from_lon) / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
@@ -0,0 +1,227 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use super::basemap::Way;
+use super::distance_km;
+
+// an easy pace, with the waiting at crossings
+const WALKING_SPEED_KMH: f64 = 4.5;
+// further than this from any street the address is off the extract
+const MAX_SNAP_KM: f64 = 0.5;
+
+/**
+ * the way on foot from one point to another along the streets
+ */
+#[derive(Debug, Clone)]
+pub struct Route {
+    pub points: Vec<(f64, f64)>,
+    pub length_km: f64,
+}
+
+impl Route {
+    pub fn minutes(&self) -> f64 {
+        self.length_km / WALKING_SPEED_KMH * 60.0
+    }
+}
+
+/*
+ * a node waiting to be visited, the closest one comes out of the heap first
+ */
+struct Visit {
+    distance: f64,
+    node: usize,
+}
+
+impl PartialEq for Visit {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance.total_cmp(&other.distance).is_eq()
+    }
+}
+
+impl Eq for Visit {}
+
+impl PartialOrd for Visit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Visit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.total_cmp(&self.distance)
+    }
+}
+
+/**
+ * the walkable streets as a graph, ways meet where they share a point
+ */
+#[derive(Debug, Default)]
+pub struct StreetGraph {
+    nodes: Vec<(f64, f64)>,
+    edges: Vec<Vec<(usize, f64)>>,
+}
+
+impl StreetGraph {
+    pub fn new(ways: &[Way]) -> Self {
+        let mut graph = StreetGraph::default();
+        let mut indices = HashMap::new();
+
+        for way in ways.iter().filter(|way| way.walkable) {
+            let mut previous: Option<usize> = None;
+            for point in &way.points {
+                let node = *indices
+                    .entry((point.0.to_bits(), point.1.to_bits()))
+                    .or_insert_with(|| {
+                        graph.nodes.push(*point);
+                        graph.edges.push(vec![]);
+                        graph.nodes.len() - 1
+                    });
+
+                if let Some(previous) = previous.filter(|previous| *previous != node) {
+                    let length = distance_km(graph.nodes[previous], *point);
+                    graph.edges[previous].push((node, length));
+                    graph.edges[node].push((previous, length));
+                }
+                previous = Some(node);
+            }
+        }
+
+        graph
+    }
+
+    fn nearest(&self, point: (f64, f64)) -> Option<(usize, f64)> {
+        self.nodes
+            .iter()
+            .map(|node| distance_km(*node, point))
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .filter(|(_, distance)| *distance <= MAX_SNAP_KM)
+    }
+
+    /**
+     * the shortest walk between two (lon, lat) points, none when either is off the streets
+     * or nothing connects them
+     */
+    pub fn route(&self, from: (f64, f64), to: (f64, f64)) -> Option<Route> {
+        let (start, start_snap) = self.nearest(from)?;
+        let (goal, goal_snap) = self.nearest(to)?;
+
+        let mut distances = vec![f64::INFINITY; self.nodes.len()];
+        let mut previous = vec![None; self.nodes.len()];
+        let mut queue = BinaryHeap::new();
+        distances[start] = 0.0;
+        queue.push(Visit {
+            distance: 0.0,
+            node: start,
+        });
+
+        while let Some(Visit { distance, node }) = queue.pop() {
+            if node == goal {
+                break;
+            }
+            if distance > distances[node] {
+                continue;
+            }
+
+            for (next, length) in &self.edges[node] {
+                let next_distance = distance + length;
+                if next_distance < distances[*next] {
+                    distances[*next] = next_distance;
+                    previous[*next] = Some(node);
+                    queue.push(Visit {
+                        distance: next_distance,
+                        node: *next,
+                    });
+                }
+            }
+        }
+
+        if distances[goal].is_infinite() {
+            return None;
+        }
+
+        let mut points = vec![to];
+        let mut node = Some(goal);
+        while let Some(current) = node {
+            points.push(self.nodes[current]);
+            node = previous[current];
+        }
+        points.push(from);
+        points.reverse();
+        // the ends usually sit right on a node
+        points.dedup();
+
+        Some(Route {
+            points,
+            length_km: start_snap + distances[goal] + goal_snap,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn street(points: &[(f64, f64)]) -> Way {
+        Way {
+            points: points.to_vec(),
+            street: true,
+            walkable: true,
+        }
+    }
+
+    #[test]
+    fn takes_the_shorter_way_around() {
+        // a square block, straight east then north is shorter than the detour to the west
+        let graph = StreetGraph::new(&[
+            street(&[(34.800, 32.080), (34.801, 32.080), (34.801, 32.081)]),
+            street(&[(34.800, 32.080), (34.798, 32.080), (34.798, 32.081)]),
+            street(&[(34.798, 32.081), (34.801, 32.081)]),
+        ]);
+
+        let route = graph.route((34.800, 32.080), (34.801, 32.081)).unwrap();
+
+        assert_eq!(
+            route.points,
+            vec![(34.800, 32.080), (34.801, 32.080), (34.801, 32.081)]
+        );
+        let expected = distance_km((34.800, 32.080), (34.801, 32.080))
+            + distance_km((34.801, 32.080), (34.801, 32.081));
+        assert!((route.length_km - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn skips_streets_not_walked_along() {
+        let mut motorway = street(&[(34.800, 32.080), (34.801, 32.080)]);
+        motorway.walkable = false;
+        let graph = StreetGraph::new(&[motorway]);
+
+        assert!(graph.route((34.800, 32.080), (34.801, 32.080)).is_none());
+    }
+
+    #[test]
+    fn routes_between_the_coordinates_the_map_uses() {
+        // the default address and a venue a few blocks east, as the view hands them over
+        let address = (34.811399, 32.079612);
+        let venue = (34.8150, 32.0800);
+        let graph = StreetGraph::new(&[street(&[
+            (34.8114, 32.0796),
+            (34.8130, 32.0796),
+            (34.8150, 32.0800),
+        ])]);
+
+        let route = graph.route(address, venue).unwrap();
+
+        assert_eq!(route.points.first(), Some(&address));
+        assert_eq!(route.points.last(), Some(&venue));
+        assert!((0.3..0.4).contains(&route.length_km));
+        assert!(route.minutes() > 4.0);
+    }
+
+    #[test]
+    fn needs_a_street_near_both_ends() {
+        let graph = StreetGraph::new(&[street(&[(34.80, 32.08), (34.81, 32.08)])]);
+
+        assert!(graph.route((34.80, 32.08), (34.90, 32.08)).is_none());
+    }
+}
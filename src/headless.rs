@@ -0,0 +1,136 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::Serialize;
+
+use crate::controllers::WoltAPI;
+use crate::controllers::WoltAPITypes::ResterauntItem;
+use crate::selection::{Preferences, SelectionStrategy};
+
+pub const EXIT_PICKED: i32 = 0;
+pub const EXIT_FETCH_FAILED: i32 = 1;
+pub const EXIT_NO_MATCH: i32 = 3;
+
+pub enum OutputFormat {
+    Json,
+    Text,
+}
+
+#[derive(Serialize)]
+pub struct PickedCoordinates {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+/**
+ * the stable output schema of `woltcli pick`, only ever add fields to it
+ */
+#[derive(Serialize)]
+pub struct PickedVenue {
+    pub title: String,
+    pub slug: String,
+    pub link: String,
+    pub delivery_price: String,
+    pub estimate_range: String,
+    pub categories: Vec<String>,
+    pub coordinates: PickedCoordinates,
+    pub seed: u64,
+}
+
+impl PickedVenue {
+    fn from_item(item: &ResterauntItem, seed: u64) -> Self {
+        let location = &item.venue.location; // [lon, lat]
+
+        PickedVenue {
+            title: item.title.clone(),
+            slug: item.venue.slug.clone(),
+            link: item.wolt_url(),
+            delivery_price: item.venue.delivery_price.clone(),
+            estimate_range: item.venue.estimate_range.clone(),
+            categories: item.filtering.filters[0].values.clone(),
+            coordinates: PickedCoordinates {
+                lat: location[1],
+                lon: location[0],
+            },
+            seed,
+        }
+    }
+
+    fn to_text(&self) -> String {
+        format!(
+            "title: {}\nslug: {}\nlink: {}\ndelivery_price: {}\nestimate_range: {}\ncategories: {}\ncoordinates: {}, {}\nseed: {}",
+            self.title,
+            self.slug,
+            self.link,
+            self.delivery_price,
+            self.estimate_range,
+            self.categories.join(", "),
+            self.coordinates.lat,
+            self.coordinates.lon,
+            self.seed
+        )
+    }
+}
+
+/**
+ * picks a restaurant without any ui and prints it, returns the exit code
+ */
+pub async fn pick(
+    address: (f32, f32),
+    preferences: &Preferences,
+    strategy: SelectionStrategy,
+    format: OutputFormat,
+    seed: u64,
+) -> i32 {
+    let (lat, lon) = address;
+    let restaurants = match WoltAPI::new(lat, lon).get_all_resteraunts().await {
+        Ok(restaurants) => restaurants,
+        Err(error) => {
+            eprintln!("failed to fetch restaurants: {}", error);
+            return EXIT_FETCH_FAILED;
+        }
+    };
+
+    let pool = preferences.filter(restaurants.items());
+    let Some(choice) = strategy.choose(
+        &pool,
+        &preferences.venue_lists,
+        &mut StdRng::seed_from_u64(seed),
+    ) else {
+        eprintln!("no restaurant matches the given preferences");
+        return EXIT_NO_MATCH;
+    };
+
+    let picked = PickedVenue::from_item(choice, seed);
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string(&picked).unwrap()),
+        OutputFormat::Text => println!("{}", picked.to_text()),
+    }
+
+    EXIT_PICKED
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn keeps_the_output_schema_stable() {
+        let item = ResterauntItem::sample("falafel-place", &["falafel", "vegan"]);
+        let picked = serde_json::to_value(PickedVenue::from_item(&item, 7)).unwrap();
+
+        assert_eq!(
+            picked,
+            json!({
+                "title": "falafel-place",
+                "slug": "falafel-place",
+                "link": item.wolt_url(),
+                "delivery_price": "₪10.00",
+                "estimate_range": "20-30",
+                "categories": ["falafel", "vegan"],
+                "coordinates": { "lat": 32.0796, "lon": 34.8114 },
+                "seed": 7,
+            })
+        );
+    }
+}
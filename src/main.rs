@@ -1,15 +1,166 @@
 mod app;
+mod cli;
+mod config;
 mod controllers;
+mod dietary;
+mod geo;
+mod headless;
+mod network;
+mod opening_hours;
 mod picking_cycle;
+mod selection;
+mod taxonomy;
+mod venue_lists;
+
+use cli::{Cli, Mode, VenueList, VenueListAction};
+use std::sync::Arc;
+
+// anything that went wrong on the way, bugs still panic
+const EXIT_FAILED: i32 = 1;
 
 #[tokio::main]
-async fn run() -> Result<(), Box<dyn std::error::Error>> {
-    let mut cycle = picking_cycle::PickingCycle::new();
-    cycle.start().await;
+async fn run(
+    cli: Cli,
+    seed: u64,
+    settings: app::Settings,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // the link to print, asked for on the end screen
+    let link = match cli.mode {
+        Mode::Pick { format } => {
+            let exit_code = headless::pick(
+                picking_cycle::PickingCycle::get_addr(),
+                &cli.preferences,
+                selection::SelectionStrategy::default(),
+                format,
+                seed,
+            )
+            .await;
+            std::process::exit(exit_code);
+        }
+        Mode::VenueList { list, action } => {
+            let mut venue_lists = cli.preferences.venue_lists;
+            let changed = match (&list, action) {
+                (_, VenueListAction::List) => false,
+                (VenueList::Favorites, VenueListAction::Add(slug)) => {
+                    venue_lists.add_favorite(&slug)
+                }
+                (VenueList::Favorites, VenueListAction::Remove(slug)) => {
+                    venue_lists.remove_favorite(&slug)
+                }
+                (VenueList::Blocked, VenueListAction::Add(slug)) => venue_lists.block(&slug),
+                (VenueList::Blocked, VenueListAction::Remove(slug)) => venue_lists.unblock(&slug),
+            };
+            // only write the file back when the command changed it
+            if changed {
+                venue_lists.save()?;
+            }
+
+            let slugs = match list {
+                VenueList::Favorites => &venue_lists.favorites,
+                VenueList::Blocked => &venue_lists.blocked,
+            };
+            for slug in slugs {
+                println!("{}", slug);
+            }
+
+            return Ok(());
+        }
+        Mode::Join { address } => {
+            network::join_session(&address, seed, cli.preferences, settings).await?
+        }
+        mode => {
+            // bound before the terminal is taken over, a port in use is reported plainly
+            let session = match mode {
+                Mode::Host { port } => Some(
+                    network::HostSession::bind(port, picking_cycle::PickingCycle::get_addr())
+                        .await?,
+                ),
+                _ => None,
+            };
+
+            let mut cycle = picking_cycle::PickingCycle::new(seed, settings)?
+                .with_preferences(cli.preferences)
+                .with_category_overview(cli.category_overview);
+            if let Some(session) = session {
+                cycle = cycle.with_session(session);
+            }
+
+            match mode {
+                Mode::Local | Mode::Host { .. } => cycle.start().await?,
+                Mode::Bracket { size } => cycle.start_bracket(size).await?,
+                Mode::Swipe => cycle.start_swipe().await?,
+                Mode::Browse => cycle.start_browse().await?,
+                Mode::Pick { .. } | Mode::VenueList { .. } | Mode::Join { .. } => {
+                    unreachable!("handled without a picking cycle")
+                }
+            }
+        }
+    };
+
+    // the terminal is ours again
+    if let Some(link) = link {
+        println!("{}", link);
+    }
+    // leave a way to replay the session, on stderr so stdout is only the link
+    eprintln!("{}", seed_line(seed));
 
     Ok(())
 }
 
+fn seed_line(seed: u64) -> String {
+    format!("seed: {} (replay with --seed {})", seed, seed)
+}
+
 fn main() {
-    run().unwrap();
+    let mut cli = match Cli::parse() {
+        Ok(cli) => cli,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    // scripts picking a venue or editing the lists shouldn't wait on the base map
+    let draws_map = !matches!(cli.mode, Mode::Pick { .. } | Mode::VenueList { .. });
+
+    let loaded = taxonomy::Taxonomy::load().and_then(|taxonomy| {
+        let venue_lists = venue_lists::VenueLists::load()?;
+        let config = config::Config::load()?;
+        let mut settings = app::Settings::new(&config)?;
+        if draws_map {
+            settings = settings.with_basemap(&config)?;
+        }
+
+        Ok((taxonomy, venue_lists, settings))
+    });
+    let settings = match loaded {
+        Ok((taxonomy, venue_lists, settings)) => {
+            cli.preferences.taxonomy = Arc::new(taxonomy);
+            cli.preferences.venue_lists = venue_lists;
+
+            settings
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(2);
+        }
+    };
+
+    let seed = cli.seed.unwrap_or_else(rand::random);
+    // the venue lists don't pick anything, there's nothing to replay
+    let is_replayable = !matches!(cli.mode, Mode::VenueList { .. });
+
+    if let Err(error) = run(cli, seed, settings) {
+        // a session that was cut short is worth replaying too
+        if is_replayable {
+            eprintln!("{}", seed_line(seed));
+        }
+
+        if app::is_cancelled(error.as_ref()) {
+            std::process::exit(app::EXIT_CANCELLED);
+        }
+
+        eprintln!("{}", error);
+        std::process::exit(EXIT_FAILED);
+    }
 }
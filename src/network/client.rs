@@ -0,0 +1,113 @@
+use std::io::{Error, ErrorKind, Result};
+
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc::error::TryRecvError;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+use super::protocol::{encode, ClientMessage, QuestionKind, ServerMessage};
+use crate::app::{App, EndScreen, QuestionScreen, RestaurantView, Settings, WaitingScreen};
+use crate::picking_cycle::RESTAURANT_NO_INDEX;
+use crate::selection::Preferences;
+
+/*
+ * waits on the host while keeping the terminal responsive, None once the host is gone
+ */
+fn wait_for_host(
+    app: &mut App,
+    messages: &mut UnboundedReceiver<ServerMessage>,
+    message: &str,
+) -> Result<Option<ServerMessage>> {
+    app.run(&mut WaitingScreen::new(
+        message,
+        |_: &mut String| match messages.try_recv() {
+            Ok(message) => Some(Some(message)),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(None),
+        },
+    ))
+}
+
+/*
+ * mirrors the host's questions on this terminal and sends back our votes,
+ * favorites and blocks made here go to our own venue lists,
+ * returns the picked restaurant's link when asked to print it
+ */
+pub async fn join_session(
+    address: &str,
+    seed: u64,
+    mut preferences: Preferences,
+    settings: Settings,
+) -> Result<Option<String>> {
+    let stream = TcpStream::connect(address).await?;
+    let (reader, mut writer) = stream.into_split();
+
+    let (sender, mut messages) = unbounded_channel();
+    let reader_task = tokio::spawn(async move {
+        let mut lines = BufReader::new(reader).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            let message: ServerMessage = serde_json::from_str(&line)
+                .map_err(|error| Error::new(ErrorKind::InvalidData, error))?;
+            if sender.send(message).is_err() {
+                break;
+            }
+        }
+
+        Ok::<(), Error>(())
+    });
+
+    let basemap = settings.basemap.clone();
+    let mut app = App::new(settings)?;
+    let mut view: Option<RestaurantView> = None;
+    let mut waiting_message = "connected, waiting for the host to ask something...";
+
+    while let Some(message) = wait_for_host(&mut app, &mut messages, waiting_message)? {
+        match message {
+            ServerMessage::Welcome { address } => {
+                view = Some(RestaurantView::new(address, basemap.clone()));
+            }
+            ServerMessage::Question {
+                id,
+                kind,
+                question,
+                restaurant,
+                choices,
+            } => {
+                let view = view
+                    .as_mut()
+                    .ok_or_else(|| Error::new(ErrorKind::InvalidData, "question before welcome"))?;
+
+                let is_restaurant_question = matches!(kind, QuestionKind::Restaurant);
+                if is_restaurant_question {
+                    view.reset_zoom();
+                }
+
+                let mut screen =
+                    QuestionScreen::new(&question, &restaurant, choices, view, &mut preferences);
+                if is_restaurant_question {
+                    screen = screen.about_restaurant(RESTAURANT_NO_INDEX);
+                }
+                let choice_index = app.run(&mut screen)?;
+
+                let vote = ClientMessage::Vote {
+                    question_id: id,
+                    choice_index,
+                };
+                writer.write_all(encode(&vote).as_bytes()).await?;
+
+                waiting_message = "vote sent, waiting for everyone else...";
+            }
+            ServerMessage::End { message, link } => {
+                return app.run(&mut EndScreen::new(message, seed).with_link(link, app.keymap()));
+            }
+        }
+    }
+
+    reader_task.await??;
+
+    // the host quit before picking anything, that's how a session ends too
+    app.run(&mut EndScreen::new(
+        "the host closed the session".to_string(),
+        seed,
+    ))
+}
@@ -0,0 +1,371 @@
+use std::collections::HashMap;
+use std::io::Result;
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc::error::TryRecvError;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use super::protocol::{encode, ClientMessage, QuestionKind, ServerMessage};
+use crate::controllers::WoltAPITypes::ResterauntItem;
+
+enum ClientEvent {
+    Vote {
+        client_id: u64,
+        question_id: u64,
+        choice_index: usize,
+    },
+}
+
+#[derive(Default)]
+struct SharedState {
+    clients: HashMap<u64, UnboundedSender<String>>,
+    current_question: Option<String>,
+    next_client_id: u64,
+}
+
+/**
+ * the votes collected so far on one question
+ */
+pub struct Ballot {
+    question_id: u64,
+    votes: HashMap<u64, usize>,
+    expected: usize,
+}
+
+impl Ballot {
+    /**
+     * returns (received, expected)
+     */
+    pub fn progress(&self) -> (usize, usize) {
+        (self.votes.len(), self.expected)
+    }
+
+    pub fn votes(&self) -> Vec<usize> {
+        self.votes.values().copied().collect()
+    }
+}
+
+pub struct HostSession {
+    port: u16,
+    state: Arc<Mutex<SharedState>>,
+    events: UnboundedReceiver<ClientEvent>,
+    next_question_id: u64,
+}
+
+impl HostSession {
+    pub async fn bind(port: u16, address: (f32, f32)) -> Result<Self> {
+        let listener = TcpListener::bind(("0.0.0.0", port)).await?;
+        let port = listener.local_addr()?.port();
+        let state = Arc::new(Mutex::new(SharedState::default()));
+        let (events_sender, events) = unbounded_channel();
+
+        tokio::spawn(HostSession::accept_loop(
+            listener,
+            state.clone(),
+            events_sender,
+            address,
+        ));
+
+        Ok(HostSession {
+            port,
+            state,
+            events,
+            next_question_id: 0,
+        })
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    async fn accept_loop(
+        listener: TcpListener,
+        state: Arc<Mutex<SharedState>>,
+        events: UnboundedSender<ClientEvent>,
+        address: (f32, f32),
+    ) {
+        while let Ok((stream, _)) = listener.accept().await {
+            let (sender, outgoing) = unbounded_channel();
+            sender
+                .send(encode(&ServerMessage::Welcome { address }))
+                .unwrap();
+
+            let client_id = {
+                let mut state = state.lock().unwrap();
+                // late joiners still get to vote on whatever is on the table
+                if let Some(question) = &state.current_question {
+                    sender.send(question.clone()).unwrap();
+                }
+
+                let client_id = state.next_client_id;
+                state.next_client_id += 1;
+                state.clients.insert(client_id, sender);
+
+                client_id
+            };
+
+            tokio::spawn(HostSession::serve_client(
+                client_id,
+                stream,
+                outgoing,
+                state.clone(),
+                events.clone(),
+            ));
+        }
+    }
+
+    async fn serve_client(
+        client_id: u64,
+        stream: TcpStream,
+        mut outgoing: UnboundedReceiver<String>,
+        state: Arc<Mutex<SharedState>>,
+        events: UnboundedSender<ClientEvent>,
+    ) {
+        let (reader, mut writer) = stream.into_split();
+
+        tokio::spawn(async move {
+            while let Some(line) = outgoing.recv().await {
+                if writer.write_all(line.as_bytes()).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut lines = BufReader::new(reader).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            if let Ok(ClientMessage::Vote {
+                question_id,
+                choice_index,
+            }) = serde_json::from_str(&line)
+            {
+                let _ = events.send(ClientEvent::Vote {
+                    client_id,
+                    question_id,
+                    choice_index,
+                });
+            }
+        }
+
+        state.lock().unwrap().clients.remove(&client_id);
+    }
+
+    fn broadcast(&self, message: &ServerMessage) {
+        let line = encode(message);
+        for sender in self.state.lock().unwrap().clients.values() {
+            let _ = sender.send(line.clone());
+        }
+    }
+
+    /**
+     * sends the question to every client and returns its id
+     */
+    pub fn open_question(
+        &mut self,
+        kind: QuestionKind,
+        question: &str,
+        restaurant: &ResterauntItem,
+        choices: &[String],
+    ) -> u64 {
+        self.next_question_id += 1;
+        let message = ServerMessage::Question {
+            id: self.next_question_id,
+            kind,
+            question: question.to_string(),
+            restaurant: Box::new(restaurant.clone()),
+            choices: choices.to_vec(),
+        };
+
+        self.broadcast(&message);
+        self.state.lock().unwrap().current_question = Some(encode(&message));
+
+        self.next_question_id
+    }
+
+    pub fn ballot(&self, question_id: u64) -> Ballot {
+        Ballot {
+            question_id,
+            votes: HashMap::new(),
+            expected: self.state.lock().unwrap().clients.len(),
+        }
+    }
+
+    /**
+     * takes in whatever votes arrived since the last poll without blocking,
+     * returns true once every connected client voted on the ballot's question
+     */
+    pub fn poll_votes(&mut self, ballot: &mut Ballot) -> bool {
+        loop {
+            match self.events.try_recv() {
+                Ok(ClientEvent::Vote {
+                    client_id,
+                    question_id,
+                    choice_index,
+                }) if question_id == ballot.question_id => {
+                    ballot.votes.insert(client_id, choice_index);
+                }
+                Ok(_) => {}
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => return true,
+            }
+        }
+
+        let mut state = self.state.lock().unwrap();
+        ballot
+            .votes
+            .retain(|client_id, _| state.clients.contains_key(client_id));
+        ballot.expected = state.clients.len();
+
+        let is_complete = state
+            .clients
+            .keys()
+            .all(|client_id| ballot.votes.contains_key(client_id));
+        if is_complete {
+            state.current_question = None;
+        }
+
+        is_complete
+    }
+
+    pub fn end(&self, message: &str, link: &str) {
+        self.broadcast(&ServerMessage::End {
+            message: message.to_string(),
+            link: Some(link.to_string()),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::io::Lines;
+    use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+    use tokio::time::{sleep, timeout};
+
+    use super::*;
+    use crate::network::tally;
+
+    struct TestClient {
+        lines: Lines<BufReader<OwnedReadHalf>>,
+        writer: OwnedWriteHalf,
+    }
+
+    impl TestClient {
+        async fn connect(port: u16) -> Self {
+            let stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+            let (reader, writer) = stream.into_split();
+            let mut client = TestClient {
+                lines: BufReader::new(reader).lines(),
+                writer,
+            };
+
+            // once welcomed the host counts us in
+            assert!(matches!(client.next().await, ServerMessage::Welcome { .. }));
+
+            client
+        }
+
+        async fn next(&mut self) -> ServerMessage {
+            let line = self.lines.next_line().await.unwrap().unwrap();
+            serde_json::from_str(&line).unwrap()
+        }
+
+        async fn vote(&mut self, question_id: u64, choice_index: usize) {
+            let vote = ClientMessage::Vote {
+                question_id,
+                choice_index,
+            };
+            self.writer
+                .write_all(encode(&vote).as_bytes())
+                .await
+                .unwrap();
+        }
+    }
+
+    async fn wait_for_votes(session: &mut HostSession, ballot: &mut Ballot) {
+        timeout(Duration::from_secs(5), async {
+            while !session.poll_votes(ballot) {
+                sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("the ballot never completed");
+    }
+
+    #[tokio::test]
+    async fn counts_the_votes_of_whoever_is_still_connected() {
+        let mut session = HostSession::bind(0, (32.0796, 34.8114)).await.unwrap();
+        let mut clients = vec![];
+        for _ in 0..3 {
+            clients.push(TestClient::connect(session.port()).await);
+        }
+
+        let choices = vec!["yes".to_string(), "no".to_string()];
+        let question_id = session.open_question(
+            QuestionKind::Restaurant,
+            "want this one?",
+            &ResterauntItem::sample("falafel-place", &["falafel"]),
+            &choices,
+        );
+        for client in &mut clients {
+            match client.next().await {
+                ServerMessage::Question { id, .. } => assert_eq!(id, question_id),
+                message => panic!("expected the question, got {:?}", message),
+            }
+        }
+
+        let mut ballot = session.ballot(question_id);
+        assert_eq!(ballot.progress(), (0, 3));
+
+        // the last one leaves without voting, the ballot shouldn't wait on it
+        drop(clients.pop());
+        clients[0].vote(question_id, 1).await;
+        clients[1].vote(question_id, 1).await;
+        wait_for_votes(&mut session, &mut ballot).await;
+
+        assert_eq!(ballot.progress(), (2, 2));
+        assert_eq!(tally(choices.len(), 0, &ballot.votes()), 1);
+    }
+
+    #[tokio::test]
+    async fn ignores_votes_on_an_older_question() {
+        let mut session = HostSession::bind(0, (32.0796, 34.8114)).await.unwrap();
+        let mut client = TestClient::connect(session.port()).await;
+        let restaurant = ResterauntItem::sample("falafel-place", &["falafel"]);
+        let choices = vec!["yes".to_string(), "no".to_string()];
+
+        let first = session.open_question(QuestionKind::Restaurant, "first", &restaurant, &choices);
+        let second =
+            session.open_question(QuestionKind::Restaurant, "second", &restaurant, &choices);
+        let mut ballot = session.ballot(second);
+
+        client.vote(first, 1).await;
+        client.vote(second, 0).await;
+        wait_for_votes(&mut session, &mut ballot).await;
+
+        assert_eq!(ballot.votes(), vec![0]);
+    }
+
+    #[tokio::test]
+    async fn tells_every_client_how_it_ended() {
+        let session = HostSession::bind(0, (32.0796, 34.8114)).await.unwrap();
+        let mut clients = vec![
+            TestClient::connect(session.port()).await,
+            TestClient::connect(session.port()).await,
+        ];
+
+        session.end("falafel-place it is", "https://example.com/falafel-place");
+
+        for client in &mut clients {
+            match client.next().await {
+                ServerMessage::End { message, link } => {
+                    assert_eq!(message, "falafel-place it is");
+                    assert_eq!(link.as_deref(), Some("https://example.com/falafel-place"));
+                }
+                message => panic!("expected the end, got {:?}", message),
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+mod client;
+mod host;
+mod protocol;
+
+pub use client::join_session;
+pub use host::HostSession;
+pub use protocol::QuestionKind;
+
+/**
+ * picks the choice with the most votes, on a tie the host's vote wins
+ * and otherwise the earliest choice does
+ */
+pub fn tally(choices_len: usize, host_vote: usize, votes: &[usize]) -> usize {
+    let mut counts = vec![0; choices_len];
+    counts[host_vote] += 1;
+    for vote in votes {
+        if let Some(count) = counts.get_mut(*vote) {
+            *count += 1;
+        }
+    }
+
+    let max_count = counts.iter().copied().max().unwrap_or(0);
+    if counts[host_vote] == max_count {
+        return host_vote;
+    }
+
+    counts
+        .iter()
+        .position(|count| *count == max_count)
+        .unwrap_or(host_vote)
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+use crate::controllers::WoltAPITypes::ResterauntItem;
+
+/*
+ * every message travels as a single line of json
+ */
+#[derive(Debug, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum QuestionKind {
+    Restaurant,
+    Category,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    Welcome {
+        address: (f32, f32),
+    },
+    Question {
+        id: u64,
+        kind: QuestionKind,
+        question: String,
+        restaurant: Box<ResterauntItem>,
+        choices: Vec<String>,
+    },
+    End {
+        message: String,
+        // the picked restaurant, older hosts don't send it
+        #[serde(default)]
+        link: Option<String>,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    Vote {
+        question_id: u64,
+        choice_index: usize,
+    },
+}
+
+pub fn encode<T: Serialize>(message: &T) -> String {
+    let mut line = serde_json::to_string(message).unwrap();
+    line.push('\n');
+
+    line
+}
@@ -0,0 +1,203 @@
+use chrono::{Datelike, Duration, Local, NaiveDateTime, NaiveTime, Timelike, Weekday};
+use std::fmt;
+
+use crate::controllers::WoltAPITypes::ResterauntVenue;
+
+/**
+ * when we want the food, restaurants that can't make it are left out of the pool
+ */
+#[derive(Debug, Default, Clone, Copy)]
+pub enum Availability {
+    Any,
+    #[default]
+    OpenNow,
+    OrderFor(NaiveTime),
+}
+
+impl Availability {
+    /**
+     * parses a "12:30" style time, which has to be later today
+     */
+    pub fn parse_order_time(value: &str) -> Result<Self, String> {
+        let time = NaiveTime::parse_from_str(value, "%H:%M")
+            .map_err(|_| format!("invalid time: {} (expected HH:MM)", value))?;
+
+        if time <= Local::now().time() {
+            return Err(format!("{} has already passed today", value));
+        }
+
+        Ok(Availability::OrderFor(time))
+    }
+
+    pub fn allows(&self, venue: &ResterauntVenue) -> bool {
+        self.allows_at(venue, Local::now().naive_local())
+    }
+
+    fn allows_at(&self, venue: &ResterauntVenue, now: NaiveDateTime) -> bool {
+        match self {
+            Availability::Any => true,
+            Availability::OpenNow => {
+                venue.online != Some(false)
+                    && venue.delivers != Some(false)
+                    && is_open_at(venue, now).unwrap_or(true)
+            }
+            Availability::OrderFor(time) => {
+                if venue.delivers == Some(false) {
+                    return false;
+                }
+
+                // the latest we can order and still get it on time
+                let order_by =
+                    now.date().and_time(*time) - Duration::minutes(estimate_max_minutes(venue));
+                if order_by < now {
+                    return false;
+                }
+
+                // `online` only tells about right now, it's just a fallback for a later order
+                is_open_at(venue, order_by).unwrap_or(venue.online != Some(false))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Availability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Availability::Any => write!(f, "any time, closed ones included"),
+            Availability::OpenNow => write!(f, "right now"),
+            Availability::OrderFor(time) => write!(f, "delivering by {}", time.format("%H:%M")),
+        }
+    }
+}
+
+fn weekday_key(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "monday",
+        Weekday::Tue => "tuesday",
+        Weekday::Wed => "wednesday",
+        Weekday::Thu => "thursday",
+        Weekday::Fri => "friday",
+        Weekday::Sat => "saturday",
+        Weekday::Sun => "sunday",
+    }
+}
+
+/*
+ * None when the venue doesn't publish its opening times for that day
+ */
+fn is_open_at(venue: &ResterauntVenue, at: NaiveDateTime) -> Option<bool> {
+    let mut times = venue
+        .opening_times
+        .as_ref()?
+        .get(weekday_key(at.weekday()))?
+        .clone();
+    times.sort_by_key(|time| time.value.millis_since_midnight);
+
+    let millis_since_midnight = u64::from(at.num_seconds_from_midnight()) * 1000;
+
+    // a day starting with a close is still open from the night before
+    let mut is_open = times.first().is_some_and(|time| time.kind == "close");
+    for time in times
+        .iter()
+        .filter(|time| time.value.millis_since_midnight <= millis_since_midnight)
+    {
+        is_open = time.kind == "open";
+    }
+
+    Some(is_open)
+}
+
+/**
+ * the upper end of the "20-30" style estimate range, in minutes
+ */
+pub fn estimate_max_minutes(venue: &ResterauntVenue) -> i64 {
+    venue
+        .estimate_range
+        .split('-')
+        .filter_map(|minutes| minutes.trim().parse().ok())
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::controllers::WoltAPITypes::{
+        ResterauntItem, ResterauntOpeningTime, ResterauntTimeValue,
+    };
+
+    fn time(kind: &str, hour: u64, minute: u64) -> ResterauntOpeningTime {
+        ResterauntOpeningTime {
+            kind: kind.to_string(),
+            value: ResterauntTimeValue {
+                millis_since_midnight: (hour * 60 + minute) * 60 * 1000,
+            },
+        }
+    }
+
+    fn venue(monday: Vec<ResterauntOpeningTime>) -> ResterauntVenue {
+        let mut venue = ResterauntItem::sample("falafel-place", &["falafel"]).venue;
+        venue.opening_times = Some(HashMap::from([(String::from("monday"), monday)]));
+        venue
+    }
+
+    // 2024-01-01 was a monday
+    fn monday_at(hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn stays_open_past_midnight() {
+        let venue = venue(vec![time("open", 18, 0), time("close", 2, 0)]);
+
+        assert_eq!(is_open_at(&venue, monday_at(1, 0)), Some(true));
+        assert_eq!(is_open_at(&venue, monday_at(3, 0)), Some(false));
+        assert_eq!(is_open_at(&venue, monday_at(19, 0)), Some(true));
+    }
+
+    #[test]
+    fn knows_nothing_without_opening_times() {
+        let unpublished = ResterauntItem::sample("falafel-place", &["falafel"]).venue;
+        assert_eq!(is_open_at(&unpublished, monday_at(12, 0)), None);
+        assert!(Availability::OpenNow.allows_at(&unpublished, monday_at(12, 0)));
+
+        // only monday is published, tuesday falls back on whether it's online
+        let mut mondays_only = venue(vec![time("open", 11, 0), time("close", 14, 0)]);
+        let tuesday = monday_at(12, 0) + Duration::days(1);
+        assert_eq!(is_open_at(&mondays_only, tuesday), None);
+
+        mondays_only.online = Some(false);
+        assert!(!Availability::OpenNow.allows_at(&mondays_only, tuesday));
+    }
+
+    #[test]
+    fn orders_early_enough_for_the_estimate() {
+        // "20-30" minutes, so the order has to go in half an hour before
+        let venue = venue(vec![time("open", 11, 0), time("close", 14, 0)]);
+        let now = monday_at(10, 0);
+        let order_for = |hour, minute| {
+            Availability::OrderFor(NaiveTime::from_hms_opt(hour, minute, 0).unwrap())
+        };
+
+        assert!(order_for(14, 20).allows_at(&venue, now));
+        assert!(order_for(11, 30).allows_at(&venue, now));
+        assert!(!order_for(11, 20).allows_at(&venue, now));
+        assert!(!order_for(14, 40).allows_at(&venue, now));
+        // too soon to make it at all
+        assert!(!order_for(10, 20).allows_at(&venue, now));
+    }
+
+    #[test]
+    fn rejects_times_that_passed_or_make_no_sense() {
+        assert!(Availability::parse_order_time("00:00").is_err());
+        assert!(Availability::parse_order_time("25:00").is_err());
+        assert!(Availability::parse_order_time("noon").is_err());
+    }
+}
@@ -1,146 +1,423 @@
-use crate::app::{EndScreenApp, RestaurantDisplayApp};
+use crate::app::{
+    App, BrowseScreen, CategoryScreen, ComparisonScreen, EndScreen, MatchupScreen, QuestionScreen,
+    RestaurantView, Settings, SwipeChoice, SwipeScreen, WaitingScreen,
+};
 use crate::controllers;
 use crate::controllers::WoltAPITypes::ResterauntItem;
+use crate::network::{self, HostSession, QuestionKind};
+use crate::selection::{Preferences, SelectionStrategy};
 use controllers::WoltAPITypes::GetAllRestaurantsResponse;
 use rand::prelude::SliceRandom;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::io::{Error, ErrorKind, Result};
+
+const RESTAURANT_CHOICES: [&str; 2] = ["yes", "no"];
+pub const RESTAURANT_NO_INDEX: usize = 1;
+
+/*
+ * the preferences, the blocked venues or the api itself left nothing to pick from
+ */
+fn no_match() -> Error {
+    Error::new(
+        ErrorKind::NotFound,
+        "no restaurant matches the current preferences",
+    )
+}
 
 pub struct PickingCycle {
     address: (f32, f32), // impl!
-    liked_category: String,
-    disliked_categories: Vec<String>,
+    preferences: Preferences,
+    strategy: SelectionStrategy,
+    rng: StdRng,
+    seed: u64,
     restaurants: Option<GetAllRestaurantsResponse>,
-    restaurant_display_instance: RestaurantDisplayApp,
-    end_screen_instance: EndScreenApp,
+    app: App,
+    view: RestaurantView,
+    session: Option<HostSession>,
+    // whether to set the liked and disliked categories on an overview before starting
+    category_overview: bool,
 }
 
 impl PickingCycle {
-    pub fn new() -> Self {
+    /**
+     * every random choice of the cycle derives from `seed`, so the same seed
+     * and the same answers replay the same session
+     */
+    pub fn new(seed: u64, settings: Settings) -> Result<Self> {
         let address = PickingCycle::get_addr();
-        let restaurant_display_instance = RestaurantDisplayApp::new(address);
-        let end_screen_instance = EndScreenApp::new();
+        let basemap = settings.basemap.clone();
 
-        PickingCycle {
+        Ok(PickingCycle {
             address,
-            liked_category: String::from(""),
-            disliked_categories: vec![],
+            preferences: Preferences::default(),
+            strategy: SelectionStrategy::default(),
+            rng: StdRng::seed_from_u64(seed),
+            seed,
             restaurants: None,
-            restaurant_display_instance,
-            end_screen_instance,
-        }
+            app: App::new(settings)?,
+            view: RestaurantView::new(address, basemap),
+            session: None,
+            category_overview: false,
+        })
+    }
+
+    /**
+     * lets clients of the session vote on every question alongside us
+     */
+    pub fn with_session(mut self, session: HostSession) -> Self {
+        self.session = Some(session);
+        self
+    }
+
+    /**
+     * starts the cycle from preferences given up front, like the dietary profiles
+     */
+    pub fn with_preferences(mut self, preferences: Preferences) -> Self {
+        self.preferences = preferences;
+        self
     }
 
-    fn get_addr() -> (f32, f32) {
+    pub fn with_category_overview(mut self, category_overview: bool) -> Self {
+        self.category_overview = category_overview;
+        self
+    }
+
+    pub fn get_addr() -> (f32, f32) {
         // TODO: impl with addr
-        return (32.079612, 34.811399);
+        (32.079612, 34.811399)
     }
 
     /*
      * lazily getting the resteraunt list
      */
-    async fn get_restaurants(&mut self) -> &GetAllRestaurantsResponse {
-        match &self.restaurants {
+    async fn get_restaurants(&mut self) -> Result<&GetAllRestaurantsResponse> {
+        match self.restaurants {
             None => {
                 let (lat, lon) = self.address;
                 let api = controllers::WoltAPI::new(lat, lon);
-                let resteraunts = api.get_all_resteraunts().await.unwrap();
-                let resteraunts_option = Some(resteraunts);
-                self.restaurants = resteraunts_option;
+                let resteraunts = api.get_all_resteraunts().await.map_err(|error| {
+                    Error::other(format!("failed to fetch restaurants: {}", error))
+                })?;
 
-                &self.restaurants.as_ref().unwrap()
+                Ok(self.restaurants.insert(resteraunts))
             }
-            Some(_) => self.restaurants.as_ref().unwrap(),
+            Some(ref restaurants) => Ok(restaurants),
         }
     }
 
-    async fn get_random_restaurant_pool(&mut self) -> ResterauntItem {
-        let resteraunts_clone = self.get_restaurants().await.clone();
-        let restaurants_items = &resteraunts_clone.sections[0].items;
+    async fn get_matching_restaurants(&mut self) -> Result<Vec<ResterauntItem>> {
+        let preferences = self.preferences.clone();
+        let restaurants = self.get_restaurants().await?;
 
-        let mut matching_items = vec![];
+        Ok(preferences.filter(restaurants.items()))
+    }
 
-        for item in restaurants_items {
-            let mut is_liked = self.liked_category == ""; // if there are no liked category set - automatically flag it as true
-            let mut is_disliked = false;
-            for category in item.filtering.filters[0].values.clone() {
-                if category == self.liked_category {
-                    is_liked = true
-                }
+    /*
+     * puts every fetched venue on the map, marked by whether it still matches
+     */
+    fn show_candidates(&mut self) {
+        if let Some(restaurants) = &self.restaurants {
+            self.view
+                .set_candidates(restaurants.items(), &self.preferences);
+        }
+    }
 
-                // todo: do something smarter than cloning :P
-                for disliked_category in self.disliked_categories.clone() {
-                    if category == disliked_category {
-                        is_disliked = true;
-                    }
-                }
-            }
+    /*
+     * every category of the pool at once, seeding the preferences the questions would
+     */
+    async fn overview_categories(&mut self) -> Result<()> {
+        if !self.category_overview {
+            return Ok(());
+        }
 
-            if is_liked && !is_disliked {
-                matching_items.push(item);
-            }
+        let pool = self.get_matching_restaurants().await?;
+        // the overview only lets go once something matches
+        if pool.is_empty() {
+            return Err(no_match());
+        }
+
+        self.app.run(&mut CategoryScreen::new(
+            &pool,
+            &mut self.preferences,
+            self.app.keymap(),
+        ))
+    }
+
+    async fn get_random_restaurant_pool(&mut self) -> Result<ResterauntItem> {
+        let matching_items = self.get_matching_restaurants().await?;
+
+        self.strategy
+            .choose(
+                &matching_items,
+                &self.preferences.venue_lists,
+                &mut self.rng,
+            )
+            .cloned()
+            .ok_or_else(no_match)
+    }
+
+    /**
+     * asks a question on this terminal and, in a session, waits for everyone
+     * else to vote on it too. returns the winning choice index
+     */
+    fn ask(
+        &mut self,
+        kind: QuestionKind,
+        question: &str,
+        restaurant: &ResterauntItem,
+        choices: Vec<String>,
+    ) -> Result<usize> {
+        let question_id = self
+            .session
+            .as_mut()
+            .map(|session| session.open_question(kind, question, restaurant, &choices));
+
+        self.show_candidates();
+
+        let choices_len = choices.len();
+        let mut screen = QuestionScreen::new(
+            question,
+            restaurant,
+            choices,
+            &mut self.view,
+            &mut self.preferences,
+        );
+        let is_restaurant_question = matches!(kind, QuestionKind::Restaurant);
+        if is_restaurant_question {
+            screen = screen.about_restaurant(RESTAURANT_NO_INDEX);
         }
+        let host_vote = self.app.run(&mut screen)?;
+
+        let (Some(session), Some(question_id)) = (self.session.as_mut(), question_id) else {
+            return Ok(host_vote);
+        };
+
+        let port = session.port();
+        let mut ballot = session.ballot(question_id);
+        let votes = self.app.run(&mut WaitingScreen::new("", |message: &mut String| {
+            let is_complete = session.poll_votes(&mut ballot);
+            let (received, expected) = ballot.progress();
+            *message = format!(
+                "waiting for votes ({}/{})... \n others can join with `woltcli join <this machine>:{}`",
+                received, expected, port
+            );
 
-        let rand_index = rand::thread_rng().gen_range(0..matching_items.len());
+            is_complete.then(|| ballot.votes())
+        }))?;
+
+        // a block is final, the rest of the session can't vote the venue back in
+        let is_blocked = self
+            .preferences
+            .venue_lists
+            .is_blocked(&restaurant.venue.slug);
+        if is_restaurant_question && is_blocked {
+            return Ok(RESTAURANT_NO_INDEX);
+        }
 
-        matching_items[rand_index].clone()
+        Ok(network::tally(choices_len, host_vote, &votes))
     }
 
-    pub async fn start(&mut self) {
+    pub async fn start(&mut self) -> Result<Option<String>> {
+        self.overview_categories().await?;
         let choice: ResterauntItem;
 
         loop {
-            let random_restaurant = self.get_random_restaurant_pool().await;
-            let first_question_choices = vec![String::from("yes"), String::from("no")];
-            let first_choice_index = self
-                .restaurant_display_instance
-                .display_restaurant_question(
-                    "Do you want to eat at",
-                    &random_restaurant,
-                    first_question_choices,
-                )
-                .unwrap();
+            let random_restaurant = self.get_random_restaurant_pool().await?;
+            let first_question_choices = RESTAURANT_CHOICES.map(String::from).to_vec();
+            self.view.reset_zoom();
+            let first_choice_index = self.ask(
+                QuestionKind::Restaurant,
+                "Do you want to eat at",
+                &random_restaurant,
+                first_question_choices,
+            )?;
 
             if first_choice_index == 0 {
                 choice = random_restaurant;
                 break;
             }
 
-            let restaurant_categories = random_restaurant.filtering.filters[0].values.to_owned();
-            let random_category = restaurant_categories
-                .choose(&mut rand::thread_rng())
-                .unwrap();
+            let restaurant_categories = self
+                .preferences
+                .taxonomy
+                .normalize_all(&random_restaurant.filtering.filters[0].values);
+            // nothing to ask about a venue without categories, on to the next one
+            let Some(random_category) = restaurant_categories.choose(&mut self.rng) else {
+                continue;
+            };
 
             let second_question_choices = vec![
                 String::from("yes"),
                 String::from("no"),
                 String::from("skip"),
             ];
-            let second_choice_index = self
-                .restaurant_display_instance
-                .display_category_question(
-                    format!("are you in the mood for {} today?", random_category).as_str(),
-                    &random_restaurant,
-                    second_question_choices,
-                )
-                .unwrap();
+            let second_choice_index = self.ask(
+                QuestionKind::Category,
+                format!("are you in the mood for {} today?", random_category).as_str(),
+                &random_restaurant,
+                second_question_choices,
+            )?;
 
+            let mut answered = self.preferences.clone();
             if second_choice_index == 0 {
-                self.liked_category = random_category.to_string();
+                answered.liked_category = random_category.to_string();
             } else if second_choice_index == 1 {
-                self.disliked_categories.push(random_category.to_string());
+                answered
+                    .disliked_categories
+                    .push(random_category.to_string());
+            }
+
+            // an answer that rules out every venue left counts as a skip
+            let restaurants = self.get_restaurants().await?;
+            if !answered.filter(restaurants.items()).is_empty() {
+                self.preferences = answered;
             }
         }
-        let restaurant_name = choice.title;
+        self.finish(choice)
+    }
 
-        let restaurant_slug = choice.venue.slug;
+    /**
+     * seeds a bracket with `size` random matching restaurants and lets them
+     * face off in pairs until a single winner is left
+     */
+    pub async fn start_bracket(&mut self, size: usize) -> Result<Option<String>> {
+        self.overview_categories().await?;
+        let mut contenders = self.get_matching_restaurants().await?;
+        if contenders.is_empty() {
+            return Err(no_match());
+        }
+        self.show_candidates();
+        contenders.shuffle(&mut self.rng);
+        contenders.truncate(size.max(1));
 
-        self.restaurant_display_instance._teardown().unwrap();
+        let mut round = 1;
+        while contenders.len() > 1 {
+            let mut winners = vec![];
+            for matchup in contenders.chunks(2) {
+                if let [first, second] = matchup {
+                    let choice_index = self.app.run(&mut MatchupScreen::new(
+                        format!("round {}: which one sounds better?", round).as_str(),
+                        [first, second],
+                        &mut self.view,
+                        &self.preferences,
+                    ))?;
 
-        let end_message = format!(
-            "{} it is!, go visit https://wolt.com/en/isr/tel-aviv/restaurant/{} to order!",
-            restaurant_name, restaurant_slug
-        );
+                    winners.push(matchup[choice_index].clone());
+                } else {
+                    // odd one out gets a bye to the next round
+                    winners.push(matchup[0].clone());
+                }
+            }
+
+            contenders = winners;
+            round += 1;
+        }
+
+        self.finish(contenders.remove(0))
+    }
+
+    /**
+     * swipes through the matching restaurants building a shortlist,
+     * then picks the final one from a side by side comparison
+     */
+    pub async fn start_swipe(&mut self) -> Result<Option<String>> {
+        self.overview_categories().await?;
+        let mut pool = self.get_matching_restaurants().await?;
+        // the swiping only ends with something on the shortlist, so it needs something to swipe
+        if pool.is_empty() {
+            return Err(no_match());
+        }
+        self.show_candidates();
+        pool.shuffle(&mut self.rng);
+
+        let mut shortlist: Vec<ResterauntItem> = vec![];
+        // whether each swipe so far shortlisted, for undo to walk back through
+        let mut history: Vec<bool> = vec![];
+        let mut index = 0;
+
+        while !pool.is_empty() {
+            if index == pool.len() {
+                if !shortlist.is_empty() {
+                    break;
+                }
+
+                // went through everything without shortlisting anything? go around again
+                index = 0;
+            }
+
+            let restaurant = &pool[index];
+            let swipe_choice = self.app.run(&mut SwipeScreen::new(
+                restaurant,
+                shortlist.len(),
+                pool.len() - index,
+                &mut self.view,
+                &self.preferences,
+                self.app.keymap(),
+            ))?;
+
+            match swipe_choice {
+                SwipeChoice::Skip => history.push(false),
+                SwipeChoice::Shortlist => {
+                    shortlist.push(restaurant.clone());
+                    history.push(true);
+                }
+                SwipeChoice::Done if !shortlist.is_empty() => break,
+                // the screen holds enter back until then, nothing to skip over
+                SwipeChoice::Done => continue,
+                SwipeChoice::Undo => {
+                    if let Some(shortlisted) = history.pop() {
+                        if shortlisted {
+                            shortlist.pop();
+                        }
+                        index = (index + pool.len() - 1) % pool.len();
+                    }
+                    continue;
+                }
+            }
+
+            index += 1;
+        }
+
+        let choice_index = self
+            .app
+            .run(&mut ComparisonScreen::new(&shortlist, self.address))?;
+
+        self.finish(shortlist.swap_remove(choice_index))
+    }
+
+    /**
+     * skips the questions, every matching restaurant goes in a table to pick from
+     */
+    pub async fn start_browse(&mut self) -> Result<Option<String>> {
+        self.overview_categories().await?;
+        let mut pool = self.get_matching_restaurants().await?;
+        if pool.is_empty() {
+            return Err(no_match());
+        }
+
+        let choice_index = self.app.run(&mut BrowseScreen::new(
+            &pool,
+            self.address,
+            self.app.keymap(),
+        ))?;
+
+        self.finish(pool.swap_remove(choice_index))
+    }
+
+    /**
+     * shows the pick, returns its link when asked to print it once the terminal is restored
+     */
+    fn finish(&mut self, choice: ResterauntItem) -> Result<Option<String>> {
+        let link = choice.wolt_url();
+        let end_message = format!("{} it is!, go visit {} to order!", choice.title, link);
+
+        if let Some(session) = &self.session {
+            session.end(&end_message, &link);
+        }
 
-        self.end_screen_instance.display_end_screen(end_message);
+        self.app.run(
+            &mut EndScreen::new(end_message, self.seed).with_link(Some(link), self.app.keymap()),
+        )
     }
 }
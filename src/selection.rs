@@ -0,0 +1,217 @@
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::Rng;
+use std::sync::Arc;
+
+use crate::controllers::WoltAPITypes::ResterauntItem;
+use crate::dietary::DietaryProfile;
+use crate::opening_hours::Availability;
+use crate::taxonomy::Taxonomy;
+use crate::venue_lists::VenueLists;
+
+/**
+ * what we learned about the mood so far, restaurants are matched against it
+ */
+#[derive(Debug, Default, Clone)]
+pub struct Preferences {
+    pub liked_category: String,
+    pub disliked_categories: Vec<String>,
+    pub dietary_profiles: Vec<DietaryProfile>,
+    pub availability: Availability,
+    pub taxonomy: Arc<Taxonomy>,
+    pub venue_lists: VenueLists,
+}
+
+impl Preferences {
+    pub fn matches(&self, item: &ResterauntItem) -> bool {
+        if self.venue_lists.is_blocked(&item.venue.slug) {
+            return false;
+        }
+
+        let categories = &item.filtering.filters[0].values;
+
+        // if there are no liked category set - automatically flag it as true
+        let is_liked = self.liked_category.is_empty()
+            || categories
+                .iter()
+                .any(|category| self.taxonomy.is_within(category, &self.liked_category));
+        // disliking a category also rules out everything below it
+        let is_disliked = categories.iter().any(|category| {
+            self.disliked_categories
+                .iter()
+                .any(|disliked_category| self.taxonomy.is_within(category, disliked_category))
+        });
+
+        let is_allowed = self
+            .dietary_profiles
+            .iter()
+            .all(|profile| profile.allows(item));
+
+        is_liked && !is_disliked && is_allowed && self.availability.allows(&item.venue)
+    }
+
+    /**
+     * one line per constraint, for showing what the pool is narrowed down by
+     */
+    pub fn summary(&self) -> Vec<String> {
+        let or_none = |values: Vec<&str>| {
+            if values.is_empty() {
+                String::from("-")
+            } else {
+                values.join(", ")
+            }
+        };
+
+        let liked = match self.liked_category.as_str() {
+            "" => vec![],
+            category => vec![category],
+        };
+
+        vec![
+            format!("liked: {}", or_none(liked)),
+            format!(
+                "disliked: {}",
+                or_none(
+                    self.disliked_categories
+                        .iter()
+                        .map(String::as_str)
+                        .collect()
+                )
+            ),
+            format!(
+                "diet: {}",
+                or_none(
+                    self.dietary_profiles
+                        .iter()
+                        .map(|profile| profile.name())
+                        .collect()
+                )
+            ),
+            format!("open: {}", self.availability),
+            format!(
+                "{} favorites, {} blocked",
+                self.venue_lists.favorites.len(),
+                self.venue_lists.blocked.len()
+            ),
+        ]
+    }
+
+    pub fn filter(&self, items: &[ResterauntItem]) -> Vec<ResterauntItem> {
+        items
+            .iter()
+            .filter(|item| self.matches(item))
+            .cloned()
+            .collect()
+    }
+}
+
+const DEFAULT_FAVORITE_WEIGHT: u32 = 3;
+
+/**
+ * how a single restaurant is chosen out of the matching pool
+ */
+#[derive(Debug, Clone, Copy)]
+pub enum SelectionStrategy {
+    // favorites are `favorite_weight` times as likely to come up as any other venue
+    Weighted { favorite_weight: u32 },
+}
+
+impl Default for SelectionStrategy {
+    fn default() -> Self {
+        SelectionStrategy::Weighted {
+            favorite_weight: DEFAULT_FAVORITE_WEIGHT,
+        }
+    }
+}
+
+impl SelectionStrategy {
+    pub fn choose<'a>(
+        &self,
+        pool: &'a [ResterauntItem],
+        venue_lists: &VenueLists,
+        rng: &mut impl Rng,
+    ) -> Option<&'a ResterauntItem> {
+        match self {
+            SelectionStrategy::Weighted { favorite_weight } => {
+                let weights = pool.iter().map(|item| {
+                    if venue_lists.is_favorite(&item.venue.slug) {
+                        *favorite_weight
+                    } else {
+                        1
+                    }
+                });
+                // fails only for an empty pool
+                let distribution = WeightedIndex::new(weights).ok()?;
+
+                Some(&pool[distribution.sample(rng)])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    fn pool() -> Vec<ResterauntItem> {
+        [
+            "falafel-place",
+            "sushi-bar",
+            "pizza-corner",
+            "burger-joint",
+            "salad-stop",
+        ]
+        .iter()
+        .map(|slug| ResterauntItem::sample(slug, &["lunch"]))
+        .collect()
+    }
+
+    #[test]
+    fn picks_the_same_venue_for_the_same_seed() {
+        let pool = pool();
+        let venue_lists = VenueLists::default();
+
+        for _ in 0..3 {
+            let choice = SelectionStrategy::default()
+                .choose(&pool, &venue_lists, &mut StdRng::seed_from_u64(3))
+                .unwrap();
+            assert_eq!(choice.venue.slug, "burger-joint");
+        }
+    }
+
+    #[test]
+    fn leans_towards_favorites() {
+        let pool = pool();
+        let mut venue_lists = VenueLists::default();
+        venue_lists.add_favorite("salad-stop");
+
+        let picks = (0..7000)
+            .map(|seed| {
+                SelectionStrategy::default()
+                    .choose(&pool, &venue_lists, &mut StdRng::seed_from_u64(seed))
+                    .unwrap()
+            })
+            .filter(|choice| choice.venue.slug == "salad-stop")
+            .count();
+
+        /* a weight of 3 against four venues of 1 lands on the favorite 3 in 7 times */
+        assert!(
+            (2700..3300).contains(&picks),
+            "picked the favorite {} times",
+            picks
+        );
+    }
+
+    #[test]
+    fn has_nothing_to_pick_from_an_empty_pool() {
+        let choice = SelectionStrategy::default().choose(
+            &[],
+            &VenueLists::default(),
+            &mut StdRng::seed_from_u64(3),
+        );
+
+        assert!(choice.is_none());
+    }
+}
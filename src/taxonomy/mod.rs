@@ -0,0 +1,183 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+
+use crate::config;
+
+const DEFAULT_TAXONOMY: &str = include_str!("default.json");
+const TAXONOMY_FILE_NAME: &str = "taxonomy.json";
+
+// deep enough for any sane hierarchy, and stops a cycle in a user file from hanging us
+const MAX_DEPTH: usize = 16;
+
+/**
+ * synonyms map a raw category to its normalized name, parents map a
+ * normalized category to the broader one it belongs to ("thai" -> "asian")
+ */
+#[derive(Debug, Deserialize, Clone)]
+pub struct Taxonomy {
+    #[serde(default)]
+    synonyms: HashMap<String, String>,
+    #[serde(default)]
+    parents: HashMap<String, String>,
+}
+
+impl Default for Taxonomy {
+    fn default() -> Self {
+        Taxonomy::bundled()
+    }
+}
+
+impl Taxonomy {
+    pub fn bundled() -> Self {
+        serde_json::from_str(DEFAULT_TAXONOMY).expect("the bundled taxonomy is valid json")
+    }
+
+    /**
+     * the bundled taxonomy, extended and overridden by the user's taxonomy.json
+     */
+    pub fn load() -> Result<Self, String> {
+        let mut taxonomy = Taxonomy::bundled();
+
+        let Some(path) = config::config_dir().map(|dir| dir.join(TAXONOMY_FILE_NAME)) else {
+            return Ok(taxonomy);
+        };
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(taxonomy),
+            Err(error) => return Err(format!("failed to read {}: {}", path.display(), error)),
+        };
+        let user_taxonomy: Taxonomy = serde_json::from_str(&contents)
+            .map_err(|error| format!("invalid {}: {}", path.display(), error))?;
+        taxonomy.merge(user_taxonomy);
+
+        Ok(taxonomy)
+    }
+
+    /*
+     * the other taxonomy's entries win over ours
+     */
+    fn merge(&mut self, other: Taxonomy) {
+        for (synonym, category) in other.synonyms {
+            self.synonyms
+                .insert(Taxonomy::clean(&synonym), Taxonomy::clean(&category));
+        }
+        for (category, parent) in other.parents {
+            self.parents
+                .insert(Taxonomy::clean(&category), Taxonomy::clean(&parent));
+        }
+    }
+
+    fn clean(raw: &str) -> String {
+        raw.trim().to_lowercase()
+    }
+
+    pub fn normalize(&self, raw: &str) -> String {
+        let category = Taxonomy::clean(raw);
+
+        match self.synonyms.get(&category) {
+            Some(normalized) => normalized.clone(),
+            None => category,
+        }
+    }
+
+    /**
+     * whether `category` is `ancestor` itself or sits anywhere below it
+     */
+    pub fn is_within(&self, category: &str, ancestor: &str) -> bool {
+        let ancestor = self.normalize(ancestor);
+        let mut current = self.normalize(category);
+
+        for _ in 0..MAX_DEPTH {
+            if current == ancestor {
+                return true;
+            }
+
+            match self.parents.get(&current) {
+                Some(parent) => current = parent.clone(),
+                None => return false,
+            }
+        }
+
+        false
+    }
+
+    /**
+     * the normalized categories of a restaurant, without duplicates
+     */
+    pub fn normalize_all(&self, raw_categories: &[String]) -> Vec<String> {
+        let mut categories: Vec<String> = vec![];
+        for raw in raw_categories {
+            let category = self.normalize(raw);
+            if !categories.contains(&category) {
+                categories.push(category);
+            }
+        }
+
+        categories
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn taxonomy(json: &str) -> Taxonomy {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn normalizes_through_the_synonyms() {
+        let taxonomy = Taxonomy::bundled();
+
+        assert_eq!(taxonomy.normalize(" Hamburgers "), "burger");
+        assert_eq!(taxonomy.normalize("Falafel"), "falafel");
+        assert_eq!(
+            taxonomy.normalize_all(&[String::from("Burgers"), String::from("burger")]),
+            vec![String::from("burger")]
+        );
+    }
+
+    #[test]
+    fn follows_the_parents_all_the_way_up() {
+        let taxonomy = taxonomy(
+            r#"{
+                "synonyms": {"tom yum": "thai soup"},
+                "parents": {"thai soup": "thai", "thai": "asian"}
+            }"#,
+        );
+
+        assert!(taxonomy.is_within("thai soup", "asian"));
+        assert!(taxonomy.is_within("Tom Yum", "thai"));
+        assert!(taxonomy.is_within("asian", "asian"));
+        assert!(!taxonomy.is_within("asian", "thai"));
+        assert!(!taxonomy.is_within("thai", "italian"));
+    }
+
+    #[test]
+    fn gives_up_on_a_cycle() {
+        let taxonomy = taxonomy(r#"{"parents": {"a": "b", "b": "a"}}"#);
+
+        assert!(taxonomy.is_within("a", "b"));
+        assert!(!taxonomy.is_within("a", "c"));
+    }
+
+    #[test]
+    fn lets_the_user_extend_and_override_the_bundled_one() {
+        let mut merged = Taxonomy::bundled();
+        merged.merge(taxonomy(
+            r#"{
+                "synonyms": {" Schnitzel Plate ": "Schnitzel"},
+                "parents": {"Thai": "Southeast Asian", "southeast asian": "asian"}
+            }"#,
+        ));
+
+        assert_eq!(merged.normalize("schnitzel plate"), "schnitzel");
+        assert!(merged.is_within("thai", "southeast asian"));
+        assert!(merged.is_within("thai", "asian"));
+        // whatever the user didn't touch is still there
+        assert_eq!(merged.normalize("hamburgers"), "burger");
+    }
+}
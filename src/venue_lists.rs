@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::PathBuf;
+
+use crate::config;
+
+const VENUE_LISTS_FILE_NAME: &str = "venues.json";
+
+/**
+ * venues we always want considered and venues we never want to see again,
+ * both by slug and kept across sessions
+ */
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct VenueLists {
+    #[serde(default)]
+    pub favorites: Vec<String>,
+    #[serde(default)]
+    pub blocked: Vec<String>,
+}
+
+impl VenueLists {
+    fn path() -> Option<PathBuf> {
+        config::config_dir().map(|dir| dir.join(VENUE_LISTS_FILE_NAME))
+    }
+
+    pub fn load() -> Result<Self, String> {
+        let Some(path) = VenueLists::path() else {
+            return Ok(VenueLists::default());
+        };
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|error| format!("invalid {}: {}", path.display(), error)),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(VenueLists::default()),
+            Err(error) => Err(format!("failed to read {}: {}", path.display(), error)),
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = VenueLists::path()
+            .ok_or_else(|| io::Error::new(ErrorKind::NotFound, "no config directory"))?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, serde_json::to_string_pretty(self).unwrap())
+    }
+
+    pub fn is_favorite(&self, slug: &str) -> bool {
+        self.favorites.iter().any(|favorite| favorite == slug)
+    }
+
+    pub fn is_blocked(&self, slug: &str) -> bool {
+        self.blocked.iter().any(|blocked| blocked == slug)
+    }
+
+    /**
+     * favoring a blocked venue lifts the block, returns whether the lists changed
+     */
+    pub fn add_favorite(&mut self, slug: &str) -> bool {
+        let unblocked = self.unblock(slug);
+        if self.is_favorite(slug) {
+            return unblocked;
+        }
+        self.favorites.push(slug.to_string());
+        true
+    }
+
+    pub fn remove_favorite(&mut self, slug: &str) -> bool {
+        let count = self.favorites.len();
+        self.favorites.retain(|favorite| favorite != slug);
+        self.favorites.len() != count
+    }
+
+    pub fn toggle_favorite(&mut self, slug: &str) {
+        if self.is_favorite(slug) {
+            self.remove_favorite(slug);
+        } else {
+            self.add_favorite(slug);
+        }
+    }
+
+    /**
+     * blocking a favorite drops it from the favorites, the block wins
+     */
+    pub fn block(&mut self, slug: &str) -> bool {
+        let unfavored = self.remove_favorite(slug);
+        if self.is_blocked(slug) {
+            return unfavored;
+        }
+        self.blocked.push(slug.to_string());
+        true
+    }
+
+    pub fn unblock(&mut self, slug: &str) -> bool {
+        let count = self.blocked.len();
+        self.blocked.retain(|blocked| blocked != slug);
+        self.blocked.len() != count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_only_actual_changes() {
+        let mut venue_lists = VenueLists::default();
+
+        assert!(venue_lists.add_favorite("sushi-bar"));
+        assert!(!venue_lists.add_favorite("sushi-bar"));
+        assert!(venue_lists.block("sushi-bar"));
+        assert!(!venue_lists.block("sushi-bar"));
+        assert!(!venue_lists.remove_favorite("sushi-bar"));
+        assert!(venue_lists.unblock("sushi-bar"));
+        assert!(!venue_lists.unblock("sushi-bar"));
+    }
+}